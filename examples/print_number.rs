@@ -0,0 +1,26 @@
+use std::io;
+
+use block_builder::{
+    ascii_art_lib, crossterm_render,
+    font::Font,
+    styled_cell::{CellColor, StyledCell},
+};
+
+fn main() -> io::Result<()> {
+    let font = Font::thin();
+    let mut blocks = Vec::new();
+    for digit in "2023".chars() {
+        let glyph = font.glyph(digit).expect("Thin font has every digit");
+        let mut builder = glyph.map(|&taken| {
+            if taken {
+                StyledCell::block(CellColor::GREEN)
+            } else {
+                StyledCell::default()
+            }
+        });
+        blocks.push(builder.block_width(2).build());
+        blocks.push(ascii_art_lib::build_space(StyledCell::default()).build());
+    }
+
+    crossterm_render::print_line(&mut io::stdout(), &blocks)
+}
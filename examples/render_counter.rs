@@ -1,3 +1,4 @@
+use block_builder::tui_block::BigText;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
     execute,
@@ -77,11 +78,7 @@ fn ui<B: Backend>(f: &mut Frame<B>, counter: u32) {
         .title_alignment(Alignment::Center)
         .border_type(BorderType::Rounded);
 
-    let numbers = create_numbers_render(counter);
-
-    let para = Paragraph::new(numbers)
-        .block(Block::default())
-        .alignment(Alignment::Center);
+    let numbers = create_numbers_render(counter).alignment(Alignment::Center);
     let help_lines: Vec<Spans<'static>> = vec![
         Spans::from(vec![Span::styled(
             "Press q to quit",
@@ -98,11 +95,11 @@ fn ui<B: Backend>(f: &mut Frame<B>, counter: u32) {
         .alignment(Alignment::Center);
 
     f.render_widget(block, size);
-    f.render_widget(para, layout[0]);
+    f.render_widget(numbers, layout[0]);
     f.render_widget(help_text, layout[2]);
 }
 
-fn create_numbers_render(counter: u32) -> Vec<Spans<'static>> {
+fn create_numbers_render(counter: u32) -> BigText<'static> {
     use block_builder::tui_block::LineBlockBuilder;
     const BLOCK_SIZE: usize = 2;
     const NONE_COLOR: Color = Color::Black;
@@ -110,5 +107,5 @@ fn create_numbers_render(counter: u32) -> Vec<Spans<'static>> {
 
     LineBlockBuilder::new(BLOCK_SIZE, TAKEN_COLOR, NONE_COLOR)
         .number(counter)
-        .build_line()
+        .build_widget()
 }
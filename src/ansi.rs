@@ -0,0 +1,240 @@
+//! Exports grids of styled cells as text with ANSI SGR colour codes, for example to log big
+//! numbers to files or the output of CI jobs.
+
+use crate::grid_block::GridBlock;
+use crate::styled_cell::{CellColor, StyledCell};
+
+const RESET: &str = "\x1b[0m";
+/// Default colours of xterm for the 16 standard and bright colours
+const STANDARD_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+/// Levels of red, green and blue in the 6 * 6 * 6 colour cube of the 256 colour palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+const CUBE_START: u8 = 16;
+const GRAY_START: u8 = 232;
+
+/// Colours a terminal supports. Colours which are not supported are replaced by the nearest
+/// supported colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    /// The 8 standard and 8 bright colours
+    Ansi16,
+    /// Colours of the 256 colour palette
+    Ansi256,
+    /// Every colour as red, green and blue. Indexed colours are kept as they are.
+    TrueColor,
+}
+
+/// Returns the line of `blocks` side by side as text with ANSI SGR colour codes.
+/// Every row ends with a line break. Colours are only written if they differ from the
+/// neighbouring cell on the left and are reset at the end of a row.
+///
+/// **Panics** if blocks in slice `blocks` do not have the same height
+pub fn line_to_ansi(blocks: &[GridBlock<StyledCell>], mode: ColorMode) -> String {
+    let height = blocks.iter().map(GridBlock::height).max().unwrap_or(0);
+
+    let mut text = String::new();
+    for row in GridBlock::iter_top_left_bottom_right(blocks, height) {
+        let (mut fg, mut bg) = (None, None);
+        for cell in row {
+            let mut codes = Vec::new();
+            if cell.fg != fg {
+                codes.push(color_code(cell.fg, mode, Layer::Foreground));
+                fg = cell.fg;
+            }
+            if cell.bg != bg {
+                codes.push(color_code(cell.bg, mode, Layer::Background));
+                bg = cell.bg;
+            }
+            if !codes.is_empty() {
+                text.push_str(&format!("\x1b[{}m", codes.join(";")));
+            }
+            text.push(cell.symbol);
+        }
+
+        if fg.is_some() || bg.is_some() {
+            text.push_str(RESET);
+        }
+        text.push('\n');
+    }
+
+    text
+}
+
+/// Like [`line_to_ansi`] for a single grid
+pub fn grid_to_ansi(grid: &GridBlock<StyledCell>, mode: ColorMode) -> String {
+    line_to_ansi(std::slice::from_ref(grid), mode)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Layer {
+    Foreground,
+    Background,
+}
+
+fn color_code(color: Option<CellColor>, mode: ColorMode, layer: Layer) -> String {
+    let (default, standard, bright, extended) = match layer {
+        Layer::Foreground => (39, 30, 90, 38),
+        Layer::Background => (49, 40, 100, 48),
+    };
+    let Some(color) = color else {
+        return default.to_string();
+    };
+
+    match (mode, color) {
+        (ColorMode::Ansi16, color) => match nearest_standard(color) {
+            index @ 0..=7 => (standard + index).to_string(),
+            index => (bright + index - 8).to_string(),
+        },
+        (ColorMode::Ansi256, CellColor::Rgb(r, g, b)) => {
+            format!("{extended};5;{}", nearest_indexed(r, g, b))
+        }
+        (_, CellColor::Indexed(index)) => format!("{extended};5;{index}"),
+        (ColorMode::TrueColor, CellColor::Rgb(r, g, b)) => format!("{extended};2;{r};{g};{b}"),
+    }
+}
+
+/// Returns the index of the standard or bright colour nearest to `color`.
+fn nearest_standard(color: CellColor) -> u8 {
+    let rgb = match color {
+        CellColor::Indexed(index) if index < 16 => return index,
+        CellColor::Indexed(index) => indexed_to_rgb(index),
+        CellColor::Rgb(r, g, b) => (r, g, b),
+    };
+
+    (0..16)
+        .min_by_key(|&index| distance(STANDARD_COLORS[usize::from(index)], rgb))
+        .unwrap_or(0)
+}
+
+/// Returns the index of the colour cube or gray scale of the 256 colour palette nearest to the
+/// colour.
+fn nearest_indexed(r: u8, g: u8, b: u8) -> u8 {
+    let level_of = |component: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&level| CUBE_LEVELS[level].abs_diff(component))
+            .unwrap_or(0) as u8
+    };
+    let cube = CUBE_START + 36 * level_of(r) + 6 * level_of(g) + level_of(b);
+
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let gray = GRAY_START + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    [cube, gray]
+        .into_iter()
+        .min_by_key(|&index| distance(indexed_to_rgb(index), (r, g, b)))
+        .unwrap_or(cube)
+}
+
+fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => STANDARD_COLORS[usize::from(index)],
+        CUBE_START..=231 => {
+            let cube_index = index - CUBE_START;
+            (
+                CUBE_LEVELS[usize::from(cube_index / 36)],
+                CUBE_LEVELS[usize::from(cube_index / 6 % 6)],
+                CUBE_LEVELS[usize::from(cube_index % 6)],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (index - GRAY_START);
+            (level, level, level)
+        }
+    }
+}
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    [(r1, r2), (g1, g2), (b1, b2)]
+        .into_iter()
+        .map(|(left, right)| u32::from(left.abs_diff(right)).pow(2))
+        .sum()
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::ascii_art_lib;
+
+    fn create_minus(taken: CellColor) -> GridBlock<StyledCell> {
+        ascii_art_lib::build_minus(StyledCell::default(), StyledCell::block(taken)).build()
+    }
+
+    #[test]
+    fn should_merge_neighbours_with_same_style() {
+        let actual = grid_to_ansi(&create_minus(CellColor::RED), ColorMode::Ansi16);
+
+        assert_eq!("   \n   \n\x1b[41m   \x1b[0m\n   \n   \n", actual);
+    }
+
+    #[test]
+    fn should_write_colors_of_every_mode() {
+        let orange = CellColor::Rgb(255, 135, 0);
+        let minus_row = |mode| {
+            grid_to_ansi(&create_minus(orange), mode)
+                .lines()
+                .nth(2)
+                .map(str::to_string)
+        };
+
+        assert_eq!(
+            Some("\x1b[48;2;255;135;0m   \x1b[0m".to_string()),
+            minus_row(ColorMode::TrueColor)
+        );
+        assert_eq!(
+            Some("\x1b[48;5;208m   \x1b[0m".to_string()),
+            minus_row(ColorMode::Ansi256)
+        );
+        assert_eq!(
+            Some("\x1b[43m   \x1b[0m".to_string()),
+            minus_row(ColorMode::Ansi16)
+        );
+    }
+
+    #[test]
+    fn should_change_only_differing_colors_in_line() {
+        let lit = StyledCell::new('#', Some(CellColor::WHITE), Some(CellColor::Indexed(236)));
+        let ghost = StyledCell::new(
+            '#',
+            Some(CellColor::DARK_GRAY),
+            Some(CellColor::Indexed(236)),
+        );
+        let line = [
+            ascii_art_lib::build_space(lit).build(),
+            ascii_art_lib::build_space(ghost).build(),
+            ascii_art_lib::build_space(StyledCell::default()).build(),
+        ];
+
+        let actual = line_to_ansi(&line, ColorMode::Ansi256);
+
+        assert_eq!(
+            Some("\x1b[38;5;15;48;5;236m#\x1b[38;5;8m#\x1b[39;49m "),
+            actual.lines().next()
+        );
+    }
+
+    #[test]
+    fn should_find_nearest_colors() {
+        assert_eq!(16, nearest_indexed(0, 0, 0));
+        assert_eq!(231, nearest_indexed(255, 255, 255));
+        assert_eq!(244, nearest_indexed(128, 128, 128));
+        assert_eq!(9, nearest_standard(CellColor::Indexed(196)));
+        assert_eq!(4, nearest_standard(CellColor::Rgb(0, 0, 200)));
+    }
+}
@@ -1,4 +1,5 @@
-//! Provides helper functions to build block like numbers, : and empty white space seperator
+//! Provides helper functions to build block like numbers, upper case letters, signs, decimal point,
+//! : and empty white space seperator
 
 use crate::grid_block::BlockGridBuilder;
 
@@ -7,6 +8,7 @@ const TOP_CENTER: (usize, usize) = (1, 0);
 const TOP_RIGHT: (usize, usize) = (2, 0);
 
 const UPPER_LEFT: (usize, usize) = (0, 1);
+const UPPER_CENTER: (usize, usize) = (1, 1);
 const UPPER_RIGHT: (usize, usize) = (2, 1);
 
 const MIDDLE_LEFT: (usize, usize) = (0, 2);
@@ -14,6 +16,7 @@ const MIDDLE_CENTER: (usize, usize) = (1, 2);
 const MIDDLE_RIGHT: (usize, usize) = (2, 2);
 
 const LOWER_LEFT: (usize, usize) = (0, 3);
+const LOWER_CENTER: (usize, usize) = (1, 3);
 const LOWER_RIGHT: (usize, usize) = (2, 3);
 
 const BOTTOM_LEFT: (usize, usize) = (0, 4);
@@ -202,6 +205,474 @@ where
     );
     builder
 }
+#[rustfmt::skip]
+pub fn build_a<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,    TOP_RIGHT,
+            UPPER_LEFT,                 UPPER_RIGHT,
+            MIDDLE_LEFT, MIDDLE_CENTER, MIDDLE_RIGHT,
+            LOWER_LEFT,                 LOWER_RIGHT,
+            BOTTOM_LEFT,                BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_b<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,
+            UPPER_LEFT,                 UPPER_RIGHT,
+            MIDDLE_LEFT, MIDDLE_CENTER,
+            LOWER_LEFT,                 LOWER_RIGHT,
+            BOTTOM_LEFT, BOTTOM_CENTER,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_c<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,    TOP_RIGHT,
+            UPPER_LEFT,
+            MIDDLE_LEFT,
+            LOWER_LEFT,
+            BOTTOM_LEFT, BOTTOM_CENTER, BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_d<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,
+            UPPER_LEFT,                 UPPER_RIGHT,
+            MIDDLE_LEFT,                MIDDLE_RIGHT,
+            LOWER_LEFT,                 LOWER_RIGHT,
+            BOTTOM_LEFT, BOTTOM_CENTER,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_e<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,    TOP_RIGHT,
+            UPPER_LEFT,
+            MIDDLE_LEFT, MIDDLE_CENTER, MIDDLE_RIGHT,
+            LOWER_LEFT,
+            BOTTOM_LEFT, BOTTOM_CENTER, BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_f<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,    TOP_RIGHT,
+            UPPER_LEFT,
+            MIDDLE_LEFT, MIDDLE_CENTER, MIDDLE_RIGHT,
+            LOWER_LEFT,
+            BOTTOM_LEFT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_g<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,    TOP_RIGHT,
+            UPPER_LEFT,
+            MIDDLE_LEFT,                MIDDLE_RIGHT,
+            LOWER_LEFT,                 LOWER_RIGHT,
+            BOTTOM_LEFT, BOTTOM_CENTER, BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_h<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,                   TOP_RIGHT,
+            UPPER_LEFT,                 UPPER_RIGHT,
+            MIDDLE_LEFT, MIDDLE_CENTER, MIDDLE_RIGHT,
+            LOWER_LEFT,                 LOWER_RIGHT,
+            BOTTOM_LEFT,                BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_i<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,    TOP_RIGHT,
+                         UPPER_CENTER,
+                         MIDDLE_CENTER,
+                         LOWER_CENTER,
+            BOTTOM_LEFT, BOTTOM_CENTER, BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_j<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+                                        TOP_RIGHT,
+                                        UPPER_RIGHT,
+                                        MIDDLE_RIGHT,
+            LOWER_LEFT,                 LOWER_RIGHT,
+            BOTTOM_LEFT, BOTTOM_CENTER, BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_k<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,                   TOP_RIGHT,
+            UPPER_LEFT,                 UPPER_RIGHT,
+            MIDDLE_LEFT, MIDDLE_CENTER,
+            LOWER_LEFT,                 LOWER_RIGHT,
+            BOTTOM_LEFT,                BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_l<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,
+            UPPER_LEFT,
+            MIDDLE_LEFT,
+            LOWER_LEFT,
+            BOTTOM_LEFT, BOTTOM_CENTER, BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_m<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,                   TOP_RIGHT,
+            UPPER_LEFT,  UPPER_CENTER,  UPPER_RIGHT,
+            MIDDLE_LEFT, MIDDLE_CENTER, MIDDLE_RIGHT,
+            LOWER_LEFT,                 LOWER_RIGHT,
+            BOTTOM_LEFT,                BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_n<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,    TOP_RIGHT,
+            UPPER_LEFT,                 UPPER_RIGHT,
+            MIDDLE_LEFT,                MIDDLE_RIGHT,
+            LOWER_LEFT,                 LOWER_RIGHT,
+            BOTTOM_LEFT,                BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_o<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,    TOP_RIGHT,
+            UPPER_LEFT,                 UPPER_RIGHT,
+            MIDDLE_LEFT,                MIDDLE_RIGHT,
+            LOWER_LEFT,                 LOWER_RIGHT,
+            BOTTOM_LEFT, BOTTOM_CENTER, BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_p<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,    TOP_RIGHT,
+            UPPER_LEFT,                 UPPER_RIGHT,
+            MIDDLE_LEFT, MIDDLE_CENTER, MIDDLE_RIGHT,
+            LOWER_LEFT,
+            BOTTOM_LEFT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_q<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,    TOP_RIGHT,
+            UPPER_LEFT,                 UPPER_RIGHT,
+            MIDDLE_LEFT,                MIDDLE_RIGHT,
+            LOWER_LEFT,  LOWER_CENTER,  LOWER_RIGHT,
+                                        BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_r<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,
+            UPPER_LEFT,                 UPPER_RIGHT,
+            MIDDLE_LEFT, MIDDLE_CENTER,
+            LOWER_LEFT,                 LOWER_RIGHT,
+            BOTTOM_LEFT,                BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_s<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,    TOP_RIGHT,
+            UPPER_LEFT,
+            MIDDLE_LEFT, MIDDLE_CENTER, MIDDLE_RIGHT,
+                                        LOWER_RIGHT,
+            BOTTOM_LEFT, BOTTOM_CENTER, BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_t<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,    TOP_RIGHT,
+                         UPPER_CENTER,
+                         MIDDLE_CENTER,
+                         LOWER_CENTER,
+                         BOTTOM_CENTER,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_u<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,                   TOP_RIGHT,
+            UPPER_LEFT,                 UPPER_RIGHT,
+            MIDDLE_LEFT,                MIDDLE_RIGHT,
+            LOWER_LEFT,                 LOWER_RIGHT,
+            BOTTOM_LEFT, BOTTOM_CENTER, BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_v<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,                   TOP_RIGHT,
+            UPPER_LEFT,                 UPPER_RIGHT,
+            MIDDLE_LEFT,                MIDDLE_RIGHT,
+            LOWER_LEFT,                 LOWER_RIGHT,
+                         BOTTOM_CENTER,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_w<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,                   TOP_RIGHT,
+            UPPER_LEFT,                 UPPER_RIGHT,
+            MIDDLE_LEFT, MIDDLE_CENTER, MIDDLE_RIGHT,
+            LOWER_LEFT,  LOWER_CENTER,  LOWER_RIGHT,
+            BOTTOM_LEFT,                BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_x<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,                   TOP_RIGHT,
+            UPPER_LEFT,                 UPPER_RIGHT,
+                         MIDDLE_CENTER,
+            LOWER_LEFT,                 LOWER_RIGHT,
+            BOTTOM_LEFT,                BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_y<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,                   TOP_RIGHT,
+            UPPER_LEFT,                 UPPER_RIGHT,
+                         MIDDLE_CENTER,
+                         LOWER_CENTER,
+                         BOTTOM_CENTER,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_z<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            TOP_LEFT,    TOP_CENTER,    TOP_RIGHT,
+                                        UPPER_RIGHT,
+                         MIDDLE_CENTER,
+            LOWER_LEFT,
+            BOTTOM_LEFT, BOTTOM_CENTER, BOTTOM_RIGHT,
+        ],
+    );
+    builder
+}
 pub fn build_double_point<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
 where
     T: Clone,
@@ -210,12 +681,83 @@ where
     builder.set_bulk_sectors(taken_v, &[(1, 1), (1, 3)]);
     builder
 }
+#[rustfmt::skip]
+pub fn build_minus<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+            MIDDLE_LEFT, MIDDLE_CENTER, MIDDLE_RIGHT,
+        ],
+    );
+    builder
+}
+#[rustfmt::skip]
+pub fn build_plus<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(
+        taken_v,
+        &[
+                         UPPER_CENTER,
+            MIDDLE_LEFT, MIDDLE_CENTER, MIDDLE_RIGHT,
+                         LOWER_CENTER,
+        ],
+    );
+    builder
+}
+pub fn build_decimal_point<T>(default_v: T, taken_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_thin_number_base(default_v);
+    builder.set_bulk_sectors(taken_v, &[BOTTOM_CENTER]);
+    builder
+}
+
+/// Comma of 1 * `height` blocks to group digits. The lowest 2 blocks are set.
+pub fn build_small_comma<T>(default_v: T, taken_v: T, height: usize) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_space_of_size(default_v, 1, height);
+    let lowest = height.saturating_sub(2)..height;
+    for y in lowest {
+        builder.set_block_sector(0, y, taken_v.clone());
+    }
+    builder
+}
+
+/// Point of 1 * `height` blocks to group digits. The lowest block is set.
+pub fn build_small_point<T>(default_v: T, taken_v: T, height: usize) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = build_space_of_size(default_v, 1, height);
+    builder.set_block_sector(0, height.saturating_sub(1), taken_v);
+    builder
+}
+
 pub fn build_space<T>(default_v: T) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    build_space_of_size(default_v, 1, 5)
+}
+
+/// Like [`build_space`] but `width` blocks wide and `height` blocks high to seperate glyphs of
+/// other sizes.
+pub fn build_space_of_size<T>(default_v: T, width: usize, height: usize) -> BlockGridBuilder<T>
 where
     T: Clone,
 {
     let mut builder = BlockGridBuilder::with_default(default_v);
-    builder.block_size(1).blocks_in_x(1).blocks_in_y(5);
+    builder.block_size(1).blocks_in_x(width).blocks_in_y(height);
     builder
 }
 
@@ -233,62 +775,217 @@ mod testing {
     use super::*;
     #[test]
     fn should_build_1() {
-        let one = build_1(' ', '*').build();
-        insta::assert_display_snapshot!(one);
+        let one = build_1('.', '*').build();
+        insta::assert_snapshot!(one);
     }
     #[test]
     fn should_build_2() {
-        let two = build_2(' ', '*').build();
-        insta::assert_display_snapshot!(two);
+        let two = build_2('.', '*').build();
+        insta::assert_snapshot!(two);
     }
     #[test]
     fn should_build_3() {
-        let two = build_3(' ', '*').build();
-        insta::assert_display_snapshot!(two);
+        let two = build_3('.', '*').build();
+        insta::assert_snapshot!(two);
     }
     #[test]
     fn should_build_4() {
-        let four = build_4(' ', '*').build();
-        insta::assert_display_snapshot!(four);
+        let four = build_4('.', '*').build();
+        insta::assert_snapshot!(four);
     }
     #[test]
     fn should_build_5() {
-        let five = build_5(' ', '*').build();
-        insta::assert_display_snapshot!(five);
+        let five = build_5('.', '*').build();
+        insta::assert_snapshot!(five);
     }
     #[test]
     fn should_build_6() {
-        let six = build_6(' ', '*').build();
-        insta::assert_display_snapshot!(six);
+        let six = build_6('.', '*').build();
+        insta::assert_snapshot!(six);
     }
     #[test]
     fn should_build_7() {
-        let seven = build_7(' ', '*').build();
-        insta::assert_display_snapshot!(seven);
+        let seven = build_7('.', '*').build();
+        insta::assert_snapshot!(seven);
     }
     #[test]
     fn should_build_8() {
-        let eight = build_8(' ', '*').build();
-        insta::assert_display_snapshot!(eight);
+        let eight = build_8('.', '*').build();
+        insta::assert_snapshot!(eight);
     }
     #[test]
     fn should_build_9() {
-        let nine = build_9(' ', '*').build();
-        insta::assert_display_snapshot!(nine);
+        let nine = build_9('.', '*').build();
+        insta::assert_snapshot!(nine);
     }
     #[test]
     fn should_build_0() {
-        let zero = build_0(' ', '*').build();
-        insta::assert_display_snapshot!(zero);
+        let zero = build_0('.', '*').build();
+        insta::assert_snapshot!(zero);
     }
     #[test]
     fn should_build_double_point() {
-        let double_point = build_double_point(' ', '*').build();
-        insta::assert_display_snapshot!(double_point);
+        let double_point = build_double_point('.', '*').build();
+        insta::assert_snapshot!(double_point);
+    }
+    #[test]
+    fn should_build_minus() {
+        let minus = build_minus('.', '*').build();
+        insta::assert_snapshot!(minus);
+    }
+    #[test]
+    fn should_build_plus() {
+        let plus = build_plus('.', '*').build();
+        insta::assert_snapshot!(plus);
+    }
+    #[test]
+    fn should_build_decimal_point() {
+        let decimal_point = build_decimal_point('.', '*').build();
+        insta::assert_snapshot!(decimal_point);
+    }
+    #[test]
+    fn should_build_small_comma() {
+        let comma = build_small_comma('.', '*', 5).build();
+        insta::assert_snapshot!(comma);
+    }
+    #[test]
+    fn should_build_small_point() {
+        let point = build_small_point('.', '*', 5).build();
+        insta::assert_snapshot!(point);
     }
     #[test]
     fn should_space() {
-        let space = build_space(' ').build();
-        insta::assert_display_snapshot!(space);
+        let space = build_space('.').build();
+        insta::assert_snapshot!(space);
+    }
+    #[test]
+    fn should_build_a() {
+        let a = build_a('.', '*').build();
+        insta::assert_snapshot!(a);
+    }
+    #[test]
+    fn should_build_b() {
+        let b = build_b('.', '*').build();
+        insta::assert_snapshot!(b);
+    }
+    #[test]
+    fn should_build_c() {
+        let c = build_c('.', '*').build();
+        insta::assert_snapshot!(c);
+    }
+    #[test]
+    fn should_build_d() {
+        let d = build_d('.', '*').build();
+        insta::assert_snapshot!(d);
+    }
+    #[test]
+    fn should_build_e() {
+        let e = build_e('.', '*').build();
+        insta::assert_snapshot!(e);
+    }
+    #[test]
+    fn should_build_f() {
+        let f = build_f('.', '*').build();
+        insta::assert_snapshot!(f);
+    }
+    #[test]
+    fn should_build_g() {
+        let g = build_g('.', '*').build();
+        insta::assert_snapshot!(g);
+    }
+    #[test]
+    fn should_build_h() {
+        let h = build_h('.', '*').build();
+        insta::assert_snapshot!(h);
+    }
+    #[test]
+    fn should_build_i() {
+        let i = build_i('.', '*').build();
+        insta::assert_snapshot!(i);
+    }
+    #[test]
+    fn should_build_j() {
+        let j = build_j('.', '*').build();
+        insta::assert_snapshot!(j);
+    }
+    #[test]
+    fn should_build_k() {
+        let k = build_k('.', '*').build();
+        insta::assert_snapshot!(k);
+    }
+    #[test]
+    fn should_build_l() {
+        let l = build_l('.', '*').build();
+        insta::assert_snapshot!(l);
+    }
+    #[test]
+    fn should_build_m() {
+        let m = build_m('.', '*').build();
+        insta::assert_snapshot!(m);
+    }
+    #[test]
+    fn should_build_n() {
+        let n = build_n('.', '*').build();
+        insta::assert_snapshot!(n);
+    }
+    #[test]
+    fn should_build_o() {
+        let o = build_o('.', '*').build();
+        insta::assert_snapshot!(o);
+    }
+    #[test]
+    fn should_build_p() {
+        let p = build_p('.', '*').build();
+        insta::assert_snapshot!(p);
+    }
+    #[test]
+    fn should_build_q() {
+        let q = build_q('.', '*').build();
+        insta::assert_snapshot!(q);
+    }
+    #[test]
+    fn should_build_r() {
+        let r = build_r('.', '*').build();
+        insta::assert_snapshot!(r);
+    }
+    #[test]
+    fn should_build_s() {
+        let s = build_s('.', '*').build();
+        insta::assert_snapshot!(s);
+    }
+    #[test]
+    fn should_build_t() {
+        let t = build_t('.', '*').build();
+        insta::assert_snapshot!(t);
+    }
+    #[test]
+    fn should_build_u() {
+        let u = build_u('.', '*').build();
+        insta::assert_snapshot!(u);
+    }
+    #[test]
+    fn should_build_v() {
+        let v = build_v('.', '*').build();
+        insta::assert_snapshot!(v);
+    }
+    #[test]
+    fn should_build_w() {
+        let w = build_w('.', '*').build();
+        insta::assert_snapshot!(w);
+    }
+    #[test]
+    fn should_build_x() {
+        let x = build_x('.', '*').build();
+        insta::assert_snapshot!(x);
+    }
+    #[test]
+    fn should_build_y() {
+        let y = build_y('.', '*').build();
+        insta::assert_snapshot!(y);
+    }
+    #[test]
+    fn should_build_z() {
+        let z = build_z('.', '*').build();
+        insta::assert_snapshot!(z);
     }
 }
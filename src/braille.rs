@@ -0,0 +1,69 @@
+//! Converts grids into unicode braille characters. Every character contains 2 * 4 elements of a
+//! grid as dots.
+
+use crate::grid_block::GridBlock;
+
+/// Number of elements in x direction put into one braille character
+pub const DOTS_IN_X: usize = 2;
+/// Number of elements in y direction put into one braille character
+pub const DOTS_IN_Y: usize = 4;
+
+const EMPTY_BRAILLE: u32 = 0x2800;
+/// Bit of a dot inside a braille character indexed by y and then x.
+const DOT_BITS: [[u32; DOTS_IN_X]; DOTS_IN_Y] =
+    [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Returns every row of braille characters for the given `grid`.
+/// An element with true is a set dot. Elements beyond the width or height of `grid` are regarded as
+/// not set.
+///
+/// Grids of other types can be converted via [`GridBlock::map`] first.
+pub fn to_braille_rows(grid: &GridBlock<bool>) -> Vec<String> {
+    let columns = grid.width().div_ceil(DOTS_IN_X);
+    let rows = grid.height().div_ceil(DOTS_IN_Y);
+
+    (0..rows)
+        .map(|row| {
+            (0..columns)
+                .map(|column| braille_at(grid, column * DOTS_IN_X, row * DOTS_IN_Y))
+                .collect()
+        })
+        .collect()
+}
+
+fn braille_at(grid: &GridBlock<bool>, start_x: usize, start_y: usize) -> char {
+    let mut code_point = EMPTY_BRAILLE;
+    for (y, bits) in DOT_BITS.iter().enumerate() {
+        for (x, bit) in bits.iter().enumerate() {
+            if let Some(true) = grid.get(start_x + x, start_y + y) {
+                code_point |= bit;
+            }
+        }
+    }
+
+    char::from_u32(code_point).expect("Unexpected: braille code point out of unicode range")
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::ascii_art_lib;
+
+    #[test]
+    fn should_convert_to_braille() {
+        let grid = ascii_art_lib::build_8(false, true).build();
+
+        let actual = to_braille_rows(&grid);
+
+        insta::assert_yaml_snapshot!(actual);
+    }
+
+    #[test]
+    fn should_set_every_dot() {
+        let grid = ascii_art_lib::build_8(true, true).block_size(2).build();
+
+        let actual = to_braille_rows(&grid);
+
+        assert_eq!(vec!["⣿⣿⣿", "⣿⣿⣿", "⠛⠛⠛"], actual);
+    }
+}
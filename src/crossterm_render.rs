@@ -0,0 +1,285 @@
+//! Writes grids of styled cells as crossterm commands to any writer without a tui frame.
+//! Useful for command line tools which print a big number once or redraw it in place.
+
+use std::io::{self, Write};
+
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    style::{Color, Print, ResetColor, SetBackgroundColor, SetForegroundColor},
+};
+
+use crate::grid_block::GridBlock;
+use crate::styled_cell::{CellColor, StyledCell};
+
+/// Writes the line of `blocks` side by side at the current cursor position.
+/// Every row ends with a line break and the colours are reset afterwards.
+///
+/// **Panics** if blocks in slice `blocks` do not have the same height
+pub fn print_line(out: &mut impl Write, blocks: &[GridBlock<StyledCell>]) -> io::Result<()> {
+    for row in to_rows(blocks) {
+        let mut colors = ColorState::default();
+        for cell in &row {
+            colors.apply(out, cell)?;
+            queue!(out, Print(cell.symbol))?;
+        }
+        queue!(out, ResetColor, Print('\n'))?;
+    }
+
+    out.flush()
+}
+
+/// Renders lines of blocks at a fixed position of the terminal. Remembers the last rendered
+/// line so a redraw only rewrites the cells which changed since then.
+///
+/// Every symbol of a cell is expected to take exactly one terminal cell.
+#[derive(Debug, Clone)]
+pub struct CrosstermRenderer {
+    column: u16,
+    row: u16,
+    last_frame: Option<Vec<Vec<StyledCell>>>,
+}
+
+impl CrosstermRenderer {
+    /// Lines are rendered with their top left corner at `column` and `row` of the terminal.
+    pub fn new(column: u16, row: u16) -> Self {
+        Self {
+            column,
+            row,
+            last_frame: None,
+        }
+    }
+
+    /// Writes every cell of the line of `blocks` and remembers it for the next redraw.
+    ///
+    /// **Panics** if blocks in slice `blocks` do not have the same height
+    pub fn render(
+        &mut self,
+        out: &mut impl Write,
+        blocks: &[GridBlock<StyledCell>],
+    ) -> io::Result<()> {
+        self.forget_last_frame().redraw(out, blocks)
+    }
+
+    /// Writes only the cells of the line of `blocks` which differ from the last rendered line.
+    /// Every cell is written if nothing was rendered before or the size of the line changed.
+    /// Cells of a larger last line outside of the new one are overwritten with blank cells, so a
+    /// shrinking line like a counter going from 10 to 9 leaves nothing behind.
+    ///
+    /// **Panics** if blocks in slice `blocks` do not have the same height
+    pub fn redraw(
+        &mut self,
+        out: &mut impl Write,
+        blocks: &[GridBlock<StyledCell>],
+    ) -> io::Result<()> {
+        let frame = to_rows(blocks);
+        let size_of = |rows: &Vec<Vec<StyledCell>>| (rows.len(), rows.first().map_or(0, Vec::len));
+        let (height, width) = size_of(&frame);
+        let last_size = self.last_frame.as_ref().map(size_of);
+        let last_frame = self
+            .last_frame
+            .take()
+            .filter(|last| size_of(last) == (height, width));
+
+        let mut writer = CellWriter::default();
+        for (y, row) in frame.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                let unchanged = last_frame.as_ref().is_some_and(|last| &last[y][x] == cell);
+                if !unchanged {
+                    writer.write(out, self.column_at(x), self.row_at(y), (x, y), cell)?;
+                }
+            }
+        }
+
+        if let Some((last_height, last_width)) = last_size {
+            let blank = StyledCell::default();
+            for y in 0..last_height {
+                let uncovered = if y < height { width } else { 0 };
+                for x in uncovered..last_width {
+                    writer.write(out, self.column_at(x), self.row_at(y), (x, y), &blank)?;
+                }
+            }
+        }
+
+        if writer.cursor.is_some() {
+            queue!(out, ResetColor)?;
+        }
+        self.last_frame = Some(frame);
+
+        out.flush()
+    }
+
+    /// Next redraw writes every cell. Useful after the terminal was cleared.
+    pub fn forget_last_frame(&mut self) -> &mut Self {
+        self.last_frame = None;
+
+        self
+    }
+
+    fn column_at(&self, x: usize) -> u16 {
+        self.column
+            .saturating_add(u16::try_from(x).unwrap_or(u16::MAX))
+    }
+
+    fn row_at(&self, y: usize) -> u16 {
+        self.row
+            .saturating_add(u16::try_from(y).unwrap_or(u16::MAX))
+    }
+}
+
+fn to_rows(blocks: &[GridBlock<StyledCell>]) -> Vec<Vec<StyledCell>> {
+    let height = blocks.iter().map(GridBlock::height).max().unwrap_or(0);
+    GridBlock::iter_top_left_bottom_right(blocks, height)
+        .map(|row| row.into_iter().copied().collect())
+        .collect()
+}
+
+impl From<CellColor> for Color {
+    fn from(color: CellColor) -> Self {
+        match color {
+            CellColor::Indexed(index) => Color::AnsiValue(index),
+            CellColor::Rgb(r, g, b) => Color::Rgb { r, g, b },
+        }
+    }
+}
+
+/// Writes cells and moves the cursor only if the cell is not right of the last written one.
+#[derive(Debug, Default)]
+struct CellWriter {
+    colors: ColorState,
+    cursor: Option<(usize, usize)>,
+}
+
+impl CellWriter {
+    fn write(
+        &mut self,
+        out: &mut impl Write,
+        column: u16,
+        row: u16,
+        (x, y): (usize, usize),
+        cell: &StyledCell,
+    ) -> io::Result<()> {
+        if self.cursor != Some((x, y)) {
+            queue!(out, MoveTo(column, row))?;
+        }
+        self.colors.apply(out, cell)?;
+        queue!(out, Print(cell.symbol))?;
+        self.cursor = Some((x + 1, y));
+
+        Ok(())
+    }
+}
+
+/// Colours which were written last. Colours are only written if they change.
+#[derive(Debug, Default)]
+struct ColorState {
+    fg: Option<Option<CellColor>>,
+    bg: Option<Option<CellColor>>,
+}
+
+impl ColorState {
+    fn apply(&mut self, out: &mut impl Write, cell: &StyledCell) -> io::Result<()> {
+        let to_color = |color: Option<CellColor>| color.map_or(Color::Reset, Color::from);
+        if self.fg != Some(cell.fg) {
+            queue!(out, SetForegroundColor(to_color(cell.fg)))?;
+            self.fg = Some(cell.fg);
+        }
+        if self.bg != Some(cell.bg) {
+            queue!(out, SetBackgroundColor(to_color(cell.bg)))?;
+            self.bg = Some(cell.bg);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::ascii_art_lib;
+
+    fn create_one(bg: CellColor) -> [GridBlock<StyledCell>; 1] {
+        [
+            ascii_art_lib::build_1(StyledCell::block(CellColor::BLACK), StyledCell::block(bg))
+                .build(),
+        ]
+    }
+
+    fn render_with(action: impl FnOnce(&mut Vec<u8>) -> io::Result<()>) -> String {
+        let mut out = Vec::new();
+        action(&mut out).expect("Writing into a vector should not fail");
+        String::from_utf8(out).expect("Commands should be valid utf8")
+    }
+
+    #[test]
+    fn should_print_rows_with_colors() {
+        let grid = ascii_art_lib::build_minus(
+            StyledCell::default(),
+            StyledCell::new('#', Some(CellColor::Rgb(1, 2, 3)), None),
+        )
+        .build();
+
+        let actual = render_with(|out| print_line(out, &[grid]));
+
+        let lines: Vec<&str> = actual.lines().collect();
+        assert_eq!(5, lines.len());
+        assert_eq!("\x1b[39m\x1b[49m   \x1b[0m", lines[0]);
+        assert_eq!("\x1b[38;2;1;2;3m\x1b[49m###\x1b[0m", lines[2]);
+    }
+
+    #[test]
+    fn should_render_every_cell_at_position() {
+        let mut renderer = CrosstermRenderer::new(4, 2);
+
+        let actual = render_with(|out| renderer.render(out, &create_one(CellColor::WHITE)));
+
+        // Cursor moves only to the start of every row
+        assert_eq!(5, actual.matches(";5H").count());
+        assert!(actual.starts_with("\x1b[3;5H"));
+        assert!(actual.contains("\x1b[7;5H"));
+        assert_eq!(15, actual.matches(' ').count());
+    }
+
+    #[test]
+    fn should_redraw_only_changed_cells() {
+        let mut renderer = CrosstermRenderer::new(0, 0);
+        render_with(|out| renderer.render(out, &create_one(CellColor::WHITE)));
+
+        let unchanged = render_with(|out| renderer.redraw(out, &create_one(CellColor::WHITE)));
+        let changed = render_with(|out| renderer.redraw(out, &create_one(CellColor::RED)));
+
+        assert_eq!("", unchanged);
+        // Only the right column of 1 is set
+        assert_eq!(
+            "\x1b[1;3H\x1b[39m\x1b[48;5;1m \x1b[2;3H \x1b[3;3H \x1b[4;3H \x1b[5;3H \x1b[0m",
+            changed
+        );
+    }
+
+    #[test]
+    fn should_render_everything_after_size_changed() {
+        let mut renderer = CrosstermRenderer::new(0, 0);
+        render_with(|out| renderer.render(out, &create_one(CellColor::WHITE)));
+
+        let space = ascii_art_lib::build_space(StyledCell::default()).build();
+        let [one] = create_one(CellColor::WHITE);
+        let actual = render_with(|out| renderer.redraw(out, &[one, space]));
+
+        assert_eq!(20, actual.matches(' ').count());
+    }
+
+    #[test]
+    fn should_blank_cells_of_larger_last_line() {
+        let mut renderer = CrosstermRenderer::new(0, 0);
+        let space = ascii_art_lib::build_space(StyledCell::block(CellColor::RED)).build();
+        let [one] = create_one(CellColor::WHITE);
+        render_with(|out| renderer.render(out, &[one, space]));
+
+        let actual = render_with(|out| renderer.redraw(out, &create_one(CellColor::WHITE)));
+
+        // 15 cells of 1 and the 5 cells of the removed space in column 4
+        assert_eq!(20, actual.matches(' ').count());
+        assert!(actual.contains("\x1b[1;4H\x1b[49m \x1b[2;4H "));
+        assert!(actual.ends_with("\x1b[5;4H \x1b[0m"));
+    }
+}
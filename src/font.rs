@@ -0,0 +1,387 @@
+//! Fonts which provide glyphs for characters from sources other than hand written rust code.
+//! A glyph is a grid builder whose set elements are true.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+
+use crate::grid_block::BlockGridBuilder;
+
+mod bdf;
+mod built_in;
+mod figlet;
+mod text_font;
+pub use bdf::{parse_bdf_font, BdfError, BdfFont};
+pub use figlet::{parse_figlet_font, FigletError, FigletFont};
+pub use text_font::{parse_text_font, TextFontError};
+
+/// Glyph for every character of a font. A set element of a glyph is true.
+pub type FontMap = HashMap<char, BlockGridBuilder<bool>>;
+
+/// Name of the built-in font with glyphs of 3 * 5 elements from [`crate::ascii_art_lib`]
+pub const THIN: &str = "thin";
+/// Name of the built-in font with glyphs of 5 * 7 elements and thick strokes
+pub const BOLD: &str = "bold";
+/// Name of the built-in font with glyphs of 5 * 7 elements like a seven segment display
+pub const SEVEN_SEGMENT: &str = "seven-segment";
+
+/// Reasons why glyphs do not form a [`Font`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontError {
+    /// Glyph of the contained character is not as high as the font.
+    InvalidGlyphHeight {
+        symbol: char,
+        height: usize,
+        font_height: usize,
+    },
+}
+
+impl Display for FontError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidGlyphHeight {
+                symbol,
+                height,
+                font_height,
+            } => write!(
+                f,
+                "Glyph for {symbol:?} is {height} elements high instead of {font_height}"
+            ),
+        }
+    }
+}
+
+impl Error for FontError {}
+
+/// Glyphs for characters together with metrics shared by all glyphs.
+/// Every glyph is as high as the font.
+#[derive(Debug, Clone)]
+pub struct Font {
+    glyphs: FontMap,
+    height: usize,
+    spacing: usize,
+    baseline: usize,
+    kerning: HashMap<(char, char), isize>,
+}
+
+impl Font {
+    /// - `height`: Number of elements every glyph has in y direction.
+    /// - `spacing`: Number of empty elements put between 2 glyphs by default.
+    /// - `baseline`: Number of rows from the top of a glyph to the baseline including the baseline.
+    ///
+    /// # Errors
+    ///
+    /// If a glyph is not `height` elements high.
+    pub fn new(
+        glyphs: FontMap,
+        height: usize,
+        spacing: usize,
+        baseline: usize,
+    ) -> Result<Self, FontError> {
+        if let Some((&symbol, glyph)) = glyphs
+            .iter()
+            .find(|(_, glyph)| glyph.height_in_blocks() != height)
+        {
+            return Err(FontError::InvalidGlyphHeight {
+                symbol,
+                height: glyph.height_in_blocks(),
+                font_height: height,
+            });
+        }
+
+        Ok(Self {
+            glyphs,
+            height,
+            spacing,
+            baseline,
+            kerning: HashMap::new(),
+        })
+    }
+
+    /// Digits, upper case letters, `:`, `-`, `+`, `.` and white space of [`crate::ascii_art_lib`]
+    pub fn thin() -> Self {
+        Self::new(built_in::thin_glyphs(), 5, 1, 5)
+            .expect("Unexpected: built-in thin font is invalid.")
+    }
+
+    /// Digits, upper case letters, `:`, `-`, `+`, `.` and white space with strokes of 2 elements
+    pub fn bold() -> Self {
+        Self::new(built_in::bold_glyphs(), 7, 1, 7)
+            .expect("Unexpected: built-in bold font is invalid.")
+    }
+
+    /// Digits, the hexadecimal digits A to F, `:`, `-`, `.` and white space of
+    /// [`crate::seven_segment`]. Glyphs already contain the empty column of the decimal point,
+    /// so the font has no spacing.
+    pub fn seven_segment() -> Self {
+        Self::new(built_in::seven_segment_glyphs(), 7, 0, 7)
+            .expect("Unexpected: built-in seven segment font is invalid.")
+    }
+
+    pub fn glyph(&self, symbol: char) -> Option<&BlockGridBuilder<bool>> {
+        self.glyphs.get(&symbol)
+    }
+
+    pub fn glyphs(&self) -> &FontMap {
+        &self.glyphs
+    }
+
+    /// Number of elements the glyph for `symbol` takes in x direction without spacing
+    pub fn advance(&self, symbol: char) -> Option<usize> {
+        self.glyph(symbol).map(BlockGridBuilder::width_in_blocks)
+    }
+
+    /// Number of elements the widest digit takes in x direction without spacing
+    pub fn digit_advance(&self) -> Option<usize> {
+        ('0'..='9').filter_map(|digit| self.advance(digit)).max()
+    }
+
+    /// Returns the glyph for `symbol` centred within `width` elements. A remaining column is put
+    /// on the left. Glyphs which are at least `width` elements wide are returned as they are.
+    pub fn glyph_with_advance(&self, symbol: char, width: usize) -> Option<BlockGridBuilder<bool>> {
+        self.glyph(symbol).map(|glyph| pad_columns(glyph, width))
+    }
+
+    /// Returns the font with every glyph as wide as its set elements. Empty columns left and right
+    /// of the set elements are removed so a 1 takes less room than an 8.
+    /// Glyphs without any set element like white space keep their width.
+    pub fn proportional(mut self) -> Self {
+        for glyph in self.glyphs.values_mut() {
+            *glyph = trim_empty_columns(glyph);
+        }
+
+        self
+    }
+
+    /// Number of elements added to the spacing between `left` and `right` if `left` is directly
+    /// followed by `right`. Is zero for pairs without kerning.
+    pub fn kerning(&self, left: char, right: char) -> isize {
+        self.kerning.get(&(left, right)).copied().unwrap_or(0)
+    }
+
+    /// Sets the kerning between `left` and `right`. A negative `kerning` moves the glyphs closer
+    /// together. The spacing of a pair never gets smaller than zero.
+    pub fn set_kerning(&mut self, left: char, right: char, kerning: isize) -> &mut Self {
+        self.kerning.insert((left, right), kerning);
+
+        self
+    }
+
+    /// Number of empty elements put between `left` and `right` with kerning applied
+    pub fn spacing_between(&self, left: char, right: char) -> usize {
+        let spacing = self.spacing as isize + self.kerning(left, right);
+        spacing.max(0) as usize
+    }
+
+    /// Number of elements every glyph has in y direction
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of rows from the top of a glyph to the baseline including the baseline
+    pub fn baseline(&self) -> usize {
+        self.baseline
+    }
+
+    /// Number of empty elements put between 2 glyphs by default
+    pub fn spacing(&self) -> usize {
+        self.spacing
+    }
+
+    pub fn set_spacing(&mut self, spacing: usize) -> &mut Self {
+        self.spacing = spacing;
+
+        self
+    }
+}
+
+/// Returns a glyph with only the columns from the first to the last column with a set element.
+fn trim_empty_columns(glyph: &BlockGridBuilder<bool>) -> BlockGridBuilder<bool> {
+    let grid = glyph.clone().block_size(1).build();
+    let filled_columns: Vec<usize> = (0..grid.width())
+        .filter(|&x| (0..grid.height()).any(|y| grid.get(x, y) == Some(&true)))
+        .collect();
+    let (Some(&first), Some(&last)) = (filled_columns.first(), filled_columns.last()) else {
+        return glyph.clone();
+    };
+
+    let mut trimmed = BlockGridBuilder::with_default(false);
+    trimmed
+        .blocks_in_x(last - first + 1)
+        .blocks_in_y(grid.height());
+    for y in 0..grid.height() {
+        for x in first..=last {
+            if grid.get(x, y) == Some(&true) {
+                trimmed.set_block_sector(x - first, y, true);
+            }
+        }
+    }
+
+    trimmed
+}
+
+/// Returns a glyph of at least `width` columns with the columns of `glyph` in the centre.
+fn pad_columns(glyph: &BlockGridBuilder<bool>, width: usize) -> BlockGridBuilder<bool> {
+    let grid = glyph.clone().block_size(1).build();
+    if grid.width() >= width {
+        return glyph.clone();
+    }
+
+    let left = (width - grid.width()).div_ceil(2);
+    let mut padded = BlockGridBuilder::with_default(false);
+    padded.blocks_in_x(width).blocks_in_y(grid.height());
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            if grid.get(x, y) == Some(&true) {
+                padded.set_block_sector(x + left, y, true);
+            }
+        }
+    }
+
+    padded
+}
+
+/// FIGcharacters already contain their spacing.
+impl TryFrom<FigletFont> for Font {
+    type Error = FontError;
+
+    fn try_from(font: FigletFont) -> Result<Self, Self::Error> {
+        let (height, baseline) = (font.height(), font.baseline());
+        Self::new(font.into_glyphs(), height, 0, baseline)
+    }
+}
+
+/// Advance widths of BDF glyphs already contain their spacing.
+impl TryFrom<BdfFont> for Font {
+    type Error = FontError;
+
+    fn try_from(font: BdfFont) -> Result<Self, Self::Error> {
+        let (height, baseline) = (font.height(), font.ascent());
+        Self::new(font.into_glyphs(), height, 0, baseline)
+    }
+}
+
+/// Fonts accessible by their names.
+#[derive(Debug, Clone, Default)]
+pub struct FontRegistry {
+    fonts: HashMap<String, Font>,
+}
+
+impl FontRegistry {
+    /// Registry without any font
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry with the fonts [`THIN`], [`BOLD`] and [`SEVEN_SEGMENT`]
+    pub fn with_built_in() -> Self {
+        let mut registry = Self::new();
+        registry.register(THIN, Font::thin());
+        registry.register(BOLD, Font::bold());
+        registry.register(SEVEN_SEGMENT, Font::seven_segment());
+        registry
+    }
+
+    /// Returns the font which was registered under `name` before.
+    pub fn register(&mut self, name: impl Into<String>, font: Font) -> Option<Font> {
+        self.fonts.insert(name.into(), font)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Font> {
+        self.fonts.get(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.fonts.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_provide_hexadecimal_digits_in_built_in_fonts() {
+        let registry = FontRegistry::with_built_in();
+
+        for name in [THIN, BOLD, SEVEN_SEGMENT] {
+            let font = registry
+                .get(name)
+                .expect("Built-in font should be registered");
+            assert_eq!(font.height(), font.baseline(), "{name}");
+            for digit in "0123456789ABCDEF:-. ".chars() {
+                let glyph = font
+                    .glyph(digit)
+                    .unwrap_or_else(|| panic!("{name} has no glyph for {digit:?}"));
+                assert_eq!(font.height(), glyph.build().height(), "{name} {digit:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn should_trim_glyphs_to_set_elements() {
+        let font = Font::thin().proportional();
+
+        assert_eq!(Some(1), font.advance('1'));
+        assert_eq!(Some(1), font.advance(':'));
+        assert_eq!(Some(3), font.advance('8'));
+        assert_eq!(Some(1), font.advance(' '));
+        let colon = font.glyph(':').unwrap().build();
+        assert_eq!(Some(&[true][..]), colon.get_row_at(1));
+    }
+
+    #[test]
+    fn should_center_glyph_within_advance() {
+        let font = Font::thin().proportional();
+
+        assert_eq!(Some(3), font.digit_advance());
+        let one = font.glyph_with_advance('1', 4).unwrap().build();
+        assert_eq!(4, one.width());
+        assert_eq!(Some(&[false, false, true, false][..]), one.get_row_at(0));
+        let eight = font.glyph_with_advance('8', 2).unwrap();
+        assert_eq!(3, eight.width_in_blocks());
+    }
+
+    #[test]
+    fn should_apply_kerning_to_spacing() {
+        let mut font = Font::thin();
+        font.set_spacing(1)
+            .set_kerning('7', '1', -1)
+            .set_kerning('1', '1', -3)
+            .set_kerning('L', 'T', 2);
+
+        assert_eq!(0, font.spacing_between('7', '1'));
+        assert_eq!(0, font.spacing_between('1', '1'));
+        assert_eq!(3, font.spacing_between('L', 'T'));
+        assert_eq!(1, font.spacing_between('1', '7'));
+    }
+
+    #[test]
+    fn should_reject_glyph_of_other_height() {
+        let mut glyphs = built_in::thin_glyphs();
+        glyphs.insert('x', crate::ascii_art_lib::build_small_comma(false, true, 4));
+
+        let actual = Font::new(glyphs, 5, 1, 5).unwrap_err();
+
+        assert_eq!(
+            FontError::InvalidGlyphHeight {
+                symbol: 'x',
+                height: 4,
+                font_height: 5
+            },
+            actual
+        );
+    }
+
+    #[test]
+    fn should_register_font_under_name() {
+        let mut registry = FontRegistry::new();
+
+        assert!(registry.register("mine", Font::bold()).is_none());
+        assert!(registry.register("mine", Font::thin()).is_some());
+
+        assert_eq!(Some(5), registry.get("mine").map(Font::height));
+        assert_eq!(vec!["mine"], registry.names().collect::<Vec<_>>());
+        assert!(registry.get(THIN).is_none());
+    }
+}
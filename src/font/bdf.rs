@@ -0,0 +1,366 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use super::FontMap;
+use crate::grid_block::BlockGridBuilder;
+
+/// Font loaded from the Glyph Bitmap Distribution Format (.bdf) of X11.
+/// Every glyph is as wide as its advance width and as high as the font.
+/// Set bits of a bitmap are set elements of its glyph.
+#[derive(Debug, Clone)]
+pub struct BdfFont {
+    height: usize,
+    ascent: usize,
+    glyphs: FontMap,
+}
+
+impl BdfFont {
+    /// Number of elements every glyph has in y direction
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of rows above the baseline
+    pub fn ascent(&self) -> usize {
+        self.ascent
+    }
+
+    pub fn glyphs(&self) -> &FontMap {
+        &self.glyphs
+    }
+
+    pub fn into_glyphs(self) -> FontMap {
+        self.glyphs
+    }
+}
+
+/// Bounding box of a font or glyph in pixels. Offsets are relative to the origin on the baseline.
+#[derive(Debug, Clone, Copy)]
+struct BoundingBox {
+    width: usize,
+    height: usize,
+    x_offset: isize,
+    y_offset: isize,
+}
+
+/// Parses a font from the content of a .bdf file.
+///
+/// The height of every glyph is the font ascent plus the font descent. If these properties are
+/// missing, the font bounding box is used instead.
+/// Bitmaps are placed according to the offsets of their bounding boxes relative to the baseline.
+/// Pixels outside of the advance width or the font height are cut off.
+/// Glyphs without encoding or with an advance width of zero are skipped.
+///
+/// # Errors
+///
+/// See [`BdfError`]
+pub fn parse_bdf_font(source: &str) -> Result<BdfFont, BdfError> {
+    let mut lines = source
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+
+    match lines.next() {
+        Some((_, line)) if line.starts_with("STARTFONT") => (),
+        _ => return Err(BdfError::MissingStartFont),
+    }
+
+    let mut font_box = None;
+    let mut ascent = None;
+    let mut descent = None;
+    let mut glyphs = FontMap::new();
+    while let Some((number, line)) = lines.next() {
+        let (keyword, arguments) = split_keyword(line);
+        match keyword {
+            "FONTBOUNDINGBOX" => font_box = Some(parse_bounding_box(arguments, number)?),
+            "FONT_ASCENT" => ascent = Some(parse_number::<usize>(arguments, number)?),
+            "FONT_DESCENT" => descent = Some(parse_number::<usize>(arguments, number)?),
+            "STARTCHAR" => {
+                let font_box = font_box.ok_or(BdfError::MissingBoundingBox)?;
+                let (ascent, height) = vertical_metrics(font_box, ascent, descent);
+                if let Some((glyph, builder)) = parse_glyph(&mut lines, font_box, ascent, height)? {
+                    glyphs.insert(glyph, builder);
+                }
+            }
+            "ENDFONT" => break,
+            _ => (),
+        }
+    }
+
+    let font_box = font_box.ok_or(BdfError::MissingBoundingBox)?;
+    let (ascent, height) = vertical_metrics(font_box, ascent, descent);
+
+    Ok(BdfFont {
+        height,
+        ascent,
+        glyphs,
+    })
+}
+
+/// Returns ascent and height of the font. Missing ascent or descent are derived from the font
+/// bounding box.
+fn vertical_metrics(
+    font_box: BoundingBox,
+    ascent: Option<usize>,
+    descent: Option<usize>,
+) -> (usize, usize) {
+    let ascent = ascent.unwrap_or_else(|| {
+        usize::try_from(font_box.height as isize + font_box.y_offset).unwrap_or(0)
+    });
+    let descent = descent.unwrap_or_else(|| usize::try_from(-font_box.y_offset).unwrap_or(0));
+
+    (ascent, ascent + descent)
+}
+
+/// Parses lines after STARTCHAR until ENDCHAR.
+/// Returns none if the glyph has no encoding or no advance width.
+fn parse_glyph<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+    font_box: BoundingBox,
+    ascent: usize,
+    height: usize,
+) -> Result<Option<(char, BlockGridBuilder<bool>)>, BdfError> {
+    let mut encoding = None;
+    let mut advance = None;
+    let mut glyph_box = font_box;
+    loop {
+        let (number, line) = lines.next().ok_or(BdfError::UnexpectedEnd)?;
+        let (keyword, arguments) = split_keyword(line);
+        match keyword {
+            "ENCODING" => {
+                let code = arguments.split_whitespace().next().unwrap_or_default();
+                encoding = Some(parse_number::<i64>(code, number)?);
+            }
+            "DWIDTH" => {
+                let x = arguments.split_whitespace().next().unwrap_or_default();
+                advance = Some(parse_number::<usize>(x, number)?);
+            }
+            "BBX" => glyph_box = parse_bounding_box(arguments, number)?,
+            "BITMAP" => break,
+            "ENDCHAR" => return Ok(None),
+            _ => (),
+        }
+    }
+
+    let mut rows = Vec::with_capacity(glyph_box.height);
+    for _ in 0..glyph_box.height {
+        let (number, line) = lines.next().ok_or(BdfError::UnexpectedEnd)?;
+        rows.push(parse_bitmap_row(line, glyph_box.width, number)?);
+    }
+
+    match lines.next() {
+        Some((_, "ENDCHAR")) => (),
+        Some((number, _)) => return Err(BdfError::InvalidLine { line: number }),
+        None => return Err(BdfError::UnexpectedEnd),
+    }
+
+    let glyph = encoding
+        .and_then(|code| u32::try_from(code).ok())
+        .and_then(char::from_u32);
+    let advance = advance.unwrap_or(font_box.width);
+    let Some(glyph) = glyph.filter(|_| advance > 0 && height > 0) else {
+        return Ok(None);
+    };
+
+    let mut builder = BlockGridBuilder::with_default(false);
+    builder.blocks_in_x(advance).blocks_in_y(height);
+    let top = ascent as isize - (glyph_box.y_offset + glyph_box.height as isize);
+    for (row_index, row) in rows.iter().enumerate() {
+        for (column, _) in row.iter().enumerate().filter(|(_, &set)| set) {
+            let x = glyph_box.x_offset + column as isize;
+            let y = top + row_index as isize;
+            if let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) {
+                if x < advance && y < height {
+                    builder.set_block_sector(x, y, true);
+                }
+            }
+        }
+    }
+
+    Ok(Some((glyph, builder)))
+}
+
+fn split_keyword(line: &str) -> (&str, &str) {
+    line.split_once(char::is_whitespace)
+        .map(|(keyword, arguments)| (keyword, arguments.trim()))
+        .unwrap_or((line, ""))
+}
+
+fn parse_number<N: std::str::FromStr>(text: &str, line: usize) -> Result<N, BdfError> {
+    text.trim()
+        .parse()
+        .map_err(|_| BdfError::InvalidLine { line })
+}
+
+fn parse_bounding_box(arguments: &str, line: usize) -> Result<BoundingBox, BdfError> {
+    let values = arguments
+        .split_whitespace()
+        .map(|value| parse_number::<isize>(value, line))
+        .collect::<Result<Vec<isize>, BdfError>>()?;
+    match values[..] {
+        [width, height, x_offset, y_offset] if width >= 0 && height >= 0 => Ok(BoundingBox {
+            width: width as usize,
+            height: height as usize,
+            x_offset,
+            y_offset,
+        }),
+        _ => Err(BdfError::InvalidLine { line }),
+    }
+}
+
+/// Returns the first `width` bits of a hex encoded row. The most significant bit is the left most.
+fn parse_bitmap_row(row: &str, width: usize, line: usize) -> Result<Vec<bool>, BdfError> {
+    let bits = row
+        .chars()
+        .map(|digit| digit.to_digit(16).ok_or(BdfError::InvalidLine { line }))
+        .collect::<Result<Vec<u32>, BdfError>>()?
+        .into_iter()
+        .flat_map(|nibble| (0..4).rev().map(move |shift| nibble >> shift & 1 == 1));
+    let row: Vec<bool> = bits.take(width).collect();
+    if row.len() < width {
+        return Err(BdfError::InvalidLine { line });
+    }
+
+    Ok(row)
+}
+
+/// Reasons why a BDF font could not be parsed. Line numbers start at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BdfError {
+    /// First line is not STARTFONT.
+    MissingStartFont,
+    /// There is no FONTBOUNDINGBOX before the first glyph.
+    MissingBoundingBox,
+    /// Line has invalid arguments for its keyword or is an invalid bitmap row.
+    InvalidLine { line: usize },
+    /// Font ends within a glyph.
+    UnexpectedEnd,
+}
+
+impl Display for BdfError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingStartFont => write!(f, "BDF font must start with STARTFONT"),
+            Self::MissingBoundingBox => write!(f, "FONTBOUNDINGBOX is missing before glyphs"),
+            Self::InvalidLine { line } => write!(f, "Line {line}: invalid arguments"),
+            Self::UnexpectedEnd => write!(f, "Font ends within a glyph"),
+        }
+    }
+}
+
+impl Error for BdfError {}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    const FONT_5X7: &str = "STARTFONT 2.1
+FONT -Misc-Fixed-Medium-R-Normal--7-70-75-75-C-50-ISO10646-1
+SIZE 7 75 75
+FONTBOUNDINGBOX 5 7 0 -1
+STARTPROPERTIES 2
+FONT_ASCENT 6
+FONT_DESCENT 1
+ENDPROPERTIES
+CHARS 3
+STARTCHAR A
+ENCODING 65
+SWIDTH 480 0
+DWIDTH 5 0
+BBX 5 7 0 -1
+BITMAP
+20
+50
+88
+88
+F8
+88
+00
+ENDCHAR
+STARTCHAR period
+ENCODING 46
+DWIDTH 5 0
+BBX 2 2 1 -1
+BITMAP
+C0
+C0
+ENDCHAR
+STARTCHAR unencoded
+ENCODING -1
+DWIDTH 5 0
+BBX 1 1 0 0
+BITMAP
+80
+ENDCHAR
+ENDFONT
+";
+
+    fn rows_of(font: &BdfFont, glyph: char) -> Vec<String> {
+        font.glyphs()[&glyph]
+            .build()
+            .iter()
+            .map(|row| row.iter().map(|&set| if set { '#' } else { '.' }).collect())
+            .collect()
+    }
+
+    #[test]
+    fn should_parse_metrics() {
+        let font = parse_bdf_font(FONT_5X7).expect("Font should be valid");
+
+        assert_eq!(7, font.height());
+        assert_eq!(6, font.ascent());
+        assert_eq!(2, font.glyphs().len());
+
+        let font = crate::font::Font::try_from(font).expect("Glyphs should be as high as the font");
+        assert_eq!(6, font.baseline());
+    }
+
+    #[test]
+    fn should_rasterise_bitmap() {
+        let font = parse_bdf_font(FONT_5X7).expect("Font should be valid");
+
+        assert_eq!(
+            vec!["..#..", ".#.#.", "#...#", "#...#", "#####", "#...#", "....."],
+            rows_of(&font, 'A')
+        );
+    }
+
+    #[test]
+    fn should_honour_offsets() {
+        let font = parse_bdf_font(FONT_5X7).expect("Font should be valid");
+
+        assert_eq!(
+            vec![".....", ".....", ".....", ".....", ".....", ".##..", ".##.."],
+            rows_of(&font, '.')
+        );
+    }
+
+    #[test]
+    fn should_report_errors() {
+        assert_eq!(
+            BdfError::MissingStartFont,
+            parse_bdf_font("FONT x").unwrap_err()
+        );
+        assert_eq!(
+            BdfError::MissingBoundingBox,
+            parse_bdf_font("STARTFONT 2.1\nSTARTCHAR A\n").unwrap_err()
+        );
+        assert_eq!(
+            BdfError::InvalidLine { line: 2 },
+            parse_bdf_font("STARTFONT 2.1\nFONTBOUNDINGBOX 5 7\n").unwrap_err()
+        );
+        assert_eq!(
+            BdfError::UnexpectedEnd,
+            parse_bdf_font("STARTFONT 2.1\nFONTBOUNDINGBOX 5 7 0 -1\nSTARTCHAR A\nBITMAP\n20\n")
+                .unwrap_err()
+        );
+        assert_eq!(
+            BdfError::InvalidLine { line: 6 },
+            parse_bdf_font(
+                "STARTFONT 2.1\nFONTBOUNDINGBOX 5 7 0 -1\nSTARTCHAR A\nBBX 1 1 0 0\nBITMAP\nXY\n"
+            )
+            .unwrap_err()
+        );
+    }
+}
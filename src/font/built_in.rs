@@ -0,0 +1,53 @@
+use super::{parse_text_font, FontMap};
+use crate::ascii_art_lib;
+use crate::seven_segment::{self, Segments};
+
+const BOLD_5X7: &str = include_str!("bold_5x7.txt");
+
+macro_rules! insert_glyphs {
+    ($map:ident, $($symbol:literal => $func:ident),* $(,)?) => {
+        $(
+            $map.insert($symbol, ascii_art_lib::$func(false, true));
+        )*
+    };
+}
+
+pub fn thin_glyphs() -> FontMap {
+    let mut map = FontMap::new();
+    insert_glyphs! {map,
+        '0' => build_0, '1' => build_1, '2' => build_2, '3' => build_3, '4' => build_4,
+        '5' => build_5, '6' => build_6, '7' => build_7, '8' => build_8, '9' => build_9,
+        ':' => build_double_point, '-' => build_minus, '+' => build_plus,
+        '.' => build_decimal_point,
+        'A' => build_a, 'B' => build_b, 'C' => build_c, 'D' => build_d, 'E' => build_e,
+        'F' => build_f, 'G' => build_g, 'H' => build_h, 'I' => build_i, 'J' => build_j,
+        'K' => build_k, 'L' => build_l, 'M' => build_m, 'N' => build_n, 'O' => build_o,
+        'P' => build_p, 'Q' => build_q, 'R' => build_r, 'S' => build_s, 'T' => build_t,
+        'U' => build_u, 'V' => build_v, 'W' => build_w, 'X' => build_x, 'Y' => build_y,
+        'Z' => build_z,
+    }
+    map.insert(' ', ascii_art_lib::build_space(false));
+    map
+}
+
+pub fn bold_glyphs() -> FontMap {
+    parse_text_font(BOLD_5X7).expect("Unexpected: built-in bold font is invalid.")
+}
+
+pub fn seven_segment_glyphs() -> FontMap {
+    let mut map = FontMap::new();
+    for symbol in "0123456789ABCDEF- ".chars() {
+        let segments = Segments::from_char(symbol)
+            .expect("Unexpected: built-in seven segment glyph is missing.");
+        map.insert(
+            symbol,
+            seven_segment::build_segments(segments, false, false, true),
+        );
+    }
+    for symbol in [':', '.'] {
+        let separator = seven_segment::build_separator(symbol, false, true)
+            .expect("Unexpected: built-in seven segment separator is missing.");
+        map.insert(symbol, separator);
+    }
+    map
+}
@@ -0,0 +1,305 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use super::FontMap;
+use crate::grid_block::BlockGridBuilder;
+
+const SIGNATURE: &str = "flf2a";
+/// Codes of german umlauts and ß which every FIGlet font contains after printable ascii.
+const DEUTSCH_CODES: [u32; 7] = [196, 214, 220, 228, 246, 252, 223];
+
+/// Codes of the characters every FIGlet font contains in this order after the comments.
+fn required_codes() -> impl Iterator<Item = u32> {
+    (32..127).chain(DEUTSCH_CODES)
+}
+
+/// Font loaded from the FIGlet format (.flf).
+/// Every not blank character of a FIGcharacter is a set element of its glyph.
+/// Hardblanks count as blank.
+#[derive(Debug, Clone)]
+pub struct FigletFont {
+    height: usize,
+    baseline: usize,
+    glyphs: FontMap,
+}
+
+impl FigletFont {
+    /// Number of elements every glyph has in y direction
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of rows from the top of a glyph to the baseline including the baseline
+    pub fn baseline(&self) -> usize {
+        self.baseline
+    }
+
+    pub fn glyphs(&self) -> &FontMap {
+        &self.glyphs
+    }
+
+    pub fn into_glyphs(self) -> FontMap {
+        self.glyphs
+    }
+}
+
+/// Parses a FIGlet font from the content of a .flf file.
+/// The required characters and all code tagged characters are loaded.
+/// Characters without any width and negative code tags are skipped.
+///
+/// # Errors
+///
+/// See [`FigletError`]
+pub fn parse_figlet_font(source: &str) -> Result<FigletFont, FigletError> {
+    let mut lines = source
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim_end_matches('\r')));
+
+    let (_, header) = lines.next().ok_or(FigletError::InvalidHeader)?;
+    let mut header_parts = header.split_whitespace();
+    let hardblank = header_parts
+        .next()
+        .and_then(|signature| signature.strip_prefix(SIGNATURE))
+        .and_then(|hardblank| hardblank.chars().next())
+        .ok_or(FigletError::InvalidHeader)?;
+    let mut numbers = header_parts.map(|part| part.parse::<isize>());
+    let mut next_number = || {
+        numbers
+            .next()
+            .and_then(|number| number.ok())
+            .ok_or(FigletError::InvalidHeader)
+    };
+    let height = usize::try_from(next_number()?).map_err(|_| FigletError::InvalidHeader)?;
+    let baseline = usize::try_from(next_number()?).map_err(|_| FigletError::InvalidHeader)?;
+    let _max_length = next_number()?;
+    let _old_layout = next_number()?;
+    let comment_lines = usize::try_from(next_number()?).map_err(|_| FigletError::InvalidHeader)?;
+    if height == 0 || baseline == 0 || baseline > height {
+        return Err(FigletError::InvalidHeader);
+    }
+
+    for _ in 0..comment_lines {
+        lines.next().ok_or(FigletError::InvalidHeader)?;
+    }
+
+    let mut glyphs = FontMap::new();
+    let mut insert = |code: i64, rows: Vec<String>| {
+        let glyph = u32::try_from(code).ok().and_then(char::from_u32);
+        if let (Some(glyph), Some(builder)) = (glyph, create_glyph(&rows, hardblank)) {
+            glyphs.insert(glyph, builder);
+        }
+    };
+
+    for code in required_codes() {
+        let rows = read_rows(&mut lines, height)
+            .ok_or(FigletError::MissingCharacter { code: code.into() })?;
+        insert(code.into(), rows);
+    }
+
+    while let Some((line_number, line)) = lines.next() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let code = line
+            .split_whitespace()
+            .next()
+            .and_then(parse_code_tag)
+            .ok_or(FigletError::InvalidCodeTag { line: line_number })?;
+        let rows = read_rows(&mut lines, height).ok_or(FigletError::MissingCharacter { code })?;
+        insert(code, rows);
+    }
+
+    Ok(FigletFont {
+        height,
+        baseline,
+        glyphs,
+    })
+}
+
+/// Returns the next `height` rows without their end marks.
+fn read_rows<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+    height: usize,
+) -> Option<Vec<String>> {
+    (0..height)
+        .map(|_| lines.next().map(|(_, line)| strip_end_marks(line)))
+        .collect()
+}
+
+fn strip_end_marks(line: &str) -> String {
+    let line = line.trim_end();
+    match line.chars().last() {
+        Some(end_mark) => line.trim_end_matches(end_mark).to_string(),
+        None => String::new(),
+    }
+}
+
+/// Code tags are decimal, hexadecimal with 0x or octal with a leading 0.
+fn parse_code_tag(tag: &str) -> Option<i64> {
+    let (negative, tag) = match tag.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, tag),
+    };
+    let code = if let Some(hex) = tag.strip_prefix("0x").or(tag.strip_prefix("0X")) {
+        i64::from_str_radix(hex, 16)
+    } else if tag.len() > 1 && tag.starts_with('0') {
+        i64::from_str_radix(&tag[1..], 8)
+    } else {
+        tag.parse()
+    }
+    .ok()?;
+
+    Some(if negative { -code } else { code })
+}
+
+fn create_glyph(rows: &[String], hardblank: char) -> Option<BlockGridBuilder<bool>> {
+    let width = rows
+        .iter()
+        .map(|row| row.chars().count())
+        .max()
+        .unwrap_or(0);
+    if width == 0 {
+        return None;
+    }
+
+    let mut builder = BlockGridBuilder::with_default(false);
+    builder.blocks_in_x(width).blocks_in_y(rows.len());
+    for (y, row) in rows.iter().enumerate() {
+        for (x, symbol) in row.chars().enumerate() {
+            if !symbol.is_whitespace() && symbol != hardblank {
+                builder.set_block_sector(x, y, true);
+            }
+        }
+    }
+
+    Some(builder)
+}
+
+/// Reasons why a FIGlet font could not be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FigletError {
+    /// First line has not the signature with hardblank, height, baseline, max length, old layout
+    /// and number of comment lines or the file ends within the comments.
+    InvalidHeader,
+    /// Font ends before all rows of the character with the contained code were given.
+    MissingCharacter { code: i64 },
+    /// Line is not a valid code for a code tagged character.
+    InvalidCodeTag { line: usize },
+}
+
+impl Display for FigletError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidHeader => write!(f, "Header of FIGlet font is invalid"),
+            Self::MissingCharacter { code } => {
+                write!(f, "Font ends before character with code {code}")
+            }
+            Self::InvalidCodeTag { line } => write!(f, "Line {line}: invalid code tag"),
+        }
+    }
+}
+
+impl Error for FigletError {}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    const HEIGHT: usize = 3;
+
+    /// Font where every required character is a hardblank besides H.
+    fn create_font_source(code_tagged: &str) -> String {
+        let mut source = String::from("flf2a$ 3 2 5 -1 2\ncomment 1\ncomment 2\n");
+        for code in required_codes() {
+            let rows: [&str; HEIGHT] = if code == u32::from('H') {
+                ["|  |@", "|--|@", "|  |@@"]
+            } else {
+                ["$@", "$@", "$@@"]
+            };
+            source.push_str(&rows.join("\n"));
+            source.push('\n');
+        }
+        source.push_str(code_tagged);
+        source
+    }
+
+    fn rows_of(font: &FigletFont, glyph: char) -> Vec<Vec<bool>> {
+        font.glyphs()[&glyph]
+            .build()
+            .iter()
+            .map(|row| row.to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn should_parse_header() {
+        let font = parse_figlet_font(&create_font_source("")).expect("Font should be valid");
+
+        assert_eq!(3, font.height());
+        assert_eq!(2, font.baseline());
+        assert_eq!(required_codes().count(), font.glyphs().len());
+
+        let font = crate::font::Font::try_from(font).expect("Glyphs should be as high as the font");
+        assert_eq!(2, font.baseline());
+    }
+
+    #[test]
+    fn should_treat_non_blank_as_set() {
+        let font = parse_figlet_font(&create_font_source("")).expect("Font should be valid");
+
+        assert_eq!(
+            vec![
+                vec![true, false, false, true],
+                vec![true, true, true, true],
+                vec![true, false, false, true],
+            ],
+            rows_of(&font, 'H')
+        );
+        assert_eq!(
+            vec![vec![false], vec![false], vec![false]],
+            rows_of(&font, ' ')
+        );
+    }
+
+    #[test]
+    fn should_parse_code_tagged_characters() {
+        let font = parse_figlet_font(&create_font_source(
+            "0x2192  RIGHTWARDS ARROW\n -> #\n--> #\n -> ##\n-5\nx#\nx#\nx##\n0101\n#@\n@\n#@@\n",
+        ))
+        .expect("Font should be valid");
+
+        assert_eq!(
+            vec![
+                vec![false, true, true, false],
+                vec![true, true, true, false],
+                vec![false, true, true, false],
+            ],
+            rows_of(&font, '→')
+        );
+        assert_eq!(
+            vec![vec![true], vec![false], vec![true]],
+            rows_of(&font, 'A')
+        );
+        assert_eq!(required_codes().count() + 1, font.glyphs().len());
+    }
+
+    #[test]
+    fn should_report_errors() {
+        assert_eq!(
+            FigletError::InvalidHeader,
+            parse_figlet_font("flf2 3 2 5 -1 0").unwrap_err()
+        );
+        assert_eq!(
+            FigletError::MissingCharacter { code: 33 },
+            parse_figlet_font("flf2a$ 1 1 5 -1 0\n$@@\n").unwrap_err()
+        );
+        assert_eq!(
+            FigletError::InvalidCodeTag {
+                line: 4 + required_codes().count() * HEIGHT
+            },
+            parse_figlet_font(&create_font_source("abc\n")).unwrap_err()
+        );
+    }
+}
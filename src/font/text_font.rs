@@ -0,0 +1,280 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use super::FontMap;
+use crate::grid_block::BlockGridBuilder;
+
+const FILLED: char = '#';
+const EMPTY: char = '.';
+const COMMENT: &str = "//";
+
+/// Parses a font in plain text format. Every glyph is drawn as ascii art.
+///
+/// The header defines the width and height of every glyph in elements.
+/// Afterwards every glyph starts with its character in brackets followed by one row per height.
+/// A row consists of `#` for set and `.` for not set elements.
+/// Empty lines and lines starting with `//` are ignored outside of glyphs.
+///
+/// # Example
+/// ```
+/// use block_builder::font;
+///
+/// let font = font::parse_text_font(
+///     "width = 3
+///      height = 2
+///
+///      // Glyph for circumflex
+///      [^]
+///      .#.
+///      #.#
+///     ",
+/// )
+/// .unwrap();
+///
+/// let circumflex = font[&'^'].build();
+/// assert_eq!(Some(&[false, true, false][..]), circumflex.get_row_at(0));
+/// assert_eq!(Some(&[true, false, true][..]), circumflex.get_row_at(1));
+/// ```
+///
+/// # Errors
+///
+/// See [`TextFontError`]
+pub fn parse_text_font(source: &str) -> Result<FontMap, TextFontError> {
+    let mut lines = source
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with(COMMENT))
+        .peekable();
+
+    let mut width = None;
+    let mut height = None;
+    while let Some((number, line)) = lines.next_if(|(_, line)| !line.starts_with('[')) {
+        let invalid_header = TextFontError::InvalidHeader { line: number };
+        let (key, value) = line.split_once('=').ok_or(invalid_header)?;
+        let value: usize = value
+            .trim()
+            .parse()
+            .ok()
+            .filter(|&value| value > 0)
+            .ok_or(invalid_header)?;
+        match key.trim() {
+            "width" => width = Some(value),
+            "height" => height = Some(value),
+            _ => return Err(invalid_header),
+        }
+    }
+
+    let width = width.ok_or(TextFontError::MissingHeader("width"))?;
+    let height = height.ok_or(TextFontError::MissingHeader("height"))?;
+
+    let mut font = FontMap::new();
+    while let Some((number, line)) = lines.next() {
+        let glyph =
+            parse_glyph_name(line).ok_or(TextFontError::InvalidGlyphName { line: number })?;
+        if font.contains_key(&glyph) {
+            return Err(TextFontError::DuplicateGlyph {
+                line: number,
+                glyph,
+            });
+        }
+
+        let mut builder = BlockGridBuilder::with_default(false);
+        builder.blocks_in_x(width).blocks_in_y(height);
+        for y in 0..height {
+            let (number, row) = lines.next().ok_or(TextFontError::MissingRows {
+                glyph,
+                expected: height,
+                found: y,
+            })?;
+            if row.chars().count() != width {
+                return Err(TextFontError::InvalidRowWidth {
+                    line: number,
+                    expected: width,
+                });
+            }
+
+            for (x, cell) in row.chars().enumerate() {
+                match cell {
+                    FILLED => {
+                        builder.set_block_sector(x, y, true);
+                    }
+                    EMPTY => (),
+                    unknown => {
+                        return Err(TextFontError::UnknownCell {
+                            line: number,
+                            cell: unknown,
+                        })
+                    }
+                }
+            }
+        }
+
+        font.insert(glyph, builder);
+    }
+
+    Ok(font)
+}
+
+/// Returns the character of a line like `[a]`.
+fn parse_glyph_name(line: &str) -> Option<char> {
+    let mut chars = line.strip_prefix('[')?.strip_suffix(']')?.chars();
+    match (chars.next(), chars.next()) {
+        (Some(glyph), None) => Some(glyph),
+        _ => None,
+    }
+}
+
+/// Reasons why a font in text format could not be parsed. Line numbers start at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextFontError {
+    /// The header has no line for the contained key.
+    MissingHeader(&'static str),
+    /// Line in header is not width or height with a value greater than zero.
+    InvalidHeader { line: usize },
+    /// Line is not a single character in brackets where a new glyph is expected.
+    InvalidGlyphName { line: usize },
+    /// Glyph occurs a second time.
+    DuplicateGlyph { line: usize, glyph: char },
+    /// Font ends before all rows of the glyph were given.
+    MissingRows {
+        glyph: char,
+        expected: usize,
+        found: usize,
+    },
+    /// Row has not the width of the header.
+    InvalidRowWidth { line: usize, expected: usize },
+    /// Row contains other character than `#` or `.`.
+    UnknownCell { line: usize, cell: char },
+}
+
+impl Display for TextFontError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingHeader(key) => write!(f, "Header has no {key}"),
+            Self::InvalidHeader { line } => write!(
+                f,
+                "Line {line}: expected width or height with a number greater than zero"
+            ),
+            Self::InvalidGlyphName { line } => {
+                write!(f, "Line {line}: expected single character in brackets")
+            }
+            Self::DuplicateGlyph { line, glyph } => {
+                write!(f, "Line {line}: glyph {glyph:?} is already defined")
+            }
+            Self::MissingRows {
+                glyph,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Glyph {glyph:?} has only {found} of {expected} rows before end of font"
+            ),
+            Self::InvalidRowWidth { line, expected } => {
+                write!(f, "Line {line}: row must be {expected} elements wide")
+            }
+            Self::UnknownCell { line, cell } => {
+                write!(
+                    f,
+                    "Line {line}: {cell:?} is neither {FILLED:?} nor {EMPTY:?}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for TextFontError {}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::ascii_art_lib;
+
+    const THIN_FONT_EXTRACT: &str = "
+// Extract of the thin font
+width = 3
+height = 5
+
+[4]
+#.#
+#.#
+###
+..#
+..#
+
+[:]
+...
+.#.
+...
+.#.
+...
+";
+
+    #[test]
+    fn should_parse_glyphs_like_ascii_art_lib() {
+        let font = parse_text_font(THIN_FONT_EXTRACT).expect("Font should be valid");
+
+        assert_eq!(2, font.len());
+        assert_grid_eq(&ascii_art_lib::build_4(false, true), &font[&'4']);
+        assert_grid_eq(&ascii_art_lib::build_double_point(false, true), &font[&':']);
+    }
+
+    #[test]
+    fn should_parse_space_as_glyph() {
+        let font =
+            parse_text_font("width = 1\nheight = 1\n[ ]\n.\n").expect("Font should be valid");
+
+        assert_eq!(Some(&[false][..]), font[&' '].build().get_row_at(0));
+    }
+
+    #[test]
+    fn should_report_errors_with_lines() {
+        assert_error("height = 5\n[a]", TextFontError::MissingHeader("width"));
+        assert_error(
+            "width = 0\nheight = 1",
+            TextFontError::InvalidHeader { line: 1 },
+        );
+        assert_error(
+            "width = 2\nheight = 1\n\n[ab]\n##",
+            TextFontError::InvalidGlyphName { line: 4 },
+        );
+        assert_error(
+            "width = 2\nheight = 1\n[a]\n##\n[a]\n##",
+            TextFontError::DuplicateGlyph {
+                line: 5,
+                glyph: 'a',
+            },
+        );
+        assert_error(
+            "width = 2\nheight = 2\n[a]\n##",
+            TextFontError::MissingRows {
+                glyph: 'a',
+                expected: 2,
+                found: 1,
+            },
+        );
+        assert_error(
+            "width = 2\nheight = 1\n[a]\n###",
+            TextFontError::InvalidRowWidth {
+                line: 4,
+                expected: 2,
+            },
+        );
+        assert_error(
+            "width = 2\nheight = 1\n[a]\n#*",
+            TextFontError::UnknownCell { line: 4, cell: '*' },
+        );
+    }
+
+    fn assert_error(source: &str, expected: TextFontError) {
+        assert_eq!(Err(expected), parse_text_font(source).map(|_| ()));
+    }
+
+    fn assert_grid_eq(expected: &BlockGridBuilder<bool>, actual: &BlockGridBuilder<bool>) {
+        let (expected, actual) = (expected.build(), actual.build());
+        assert_eq!(
+            expected.iter().collect::<Vec<_>>(),
+            actual.iter().collect::<Vec<_>>()
+        );
+    }
+}
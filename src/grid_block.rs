@@ -4,10 +4,38 @@ use core::fmt::{Debug, Display};
 use ndarray::Array2;
 
 mod grid_block_builder;
+mod grid_block_error;
 pub use grid_block_builder::BlockGridBuilder;
+pub use grid_block_error::{Dimension, GridBlockError};
 
-/// Grid which is made up in blocks . Every block has the same size. A block has size * size
-/// elements.
+/// Number of elements a block has in x and y direction.
+/// A `usize` converts into a square block and a tuple into (width, height).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockSize {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl BlockSize {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { width, height }
+    }
+}
+
+impl From<usize> for BlockSize {
+    fn from(size: usize) -> Self {
+        Self::new(size, size)
+    }
+}
+
+impl From<(usize, usize)> for BlockSize {
+    fn from((width, height): (usize, usize)) -> Self {
+        Self::new(width, height)
+    }
+}
+
+/// Grid which is made up in blocks . Every block has the same size. A block has
+/// block width * block height elements.
 /// Every grid has a number of blocks in x and y direction.
 /// The whole API is exposed for immutable access only.
 #[derive(Debug, Clone)]
@@ -15,14 +43,16 @@ pub struct GridBlock<T> {
     grid: Array2<T>,
     width: usize,
     height: usize,
+    block_dimensions: BlockSize,
 }
 
 impl<T> GridBlock<T> {
-    fn new(grid: Array2<T>, width: usize, height: usize) -> Self {
+    fn new(grid: Array2<T>, width: usize, height: usize, block_dimensions: BlockSize) -> Self {
         Self {
             grid,
             width,
             height,
+            block_dimensions,
         }
     }
 }
@@ -38,6 +68,20 @@ impl<T> GridBlock<T> {
         self.height
     }
 
+    /// Number of elements a block has in x direction
+    pub fn block_width(&self) -> usize {
+        self.block_dimensions.width
+    }
+
+    /// Number of elements a block has in y direction
+    pub fn block_height(&self) -> usize {
+        self.block_dimensions.height
+    }
+
+    pub fn block_dimensions(&self) -> BlockSize {
+        self.block_dimensions
+    }
+
     pub fn get(&self, x: usize, y: usize) -> Option<&T> {
         self.grid.get((y, x))
     }
@@ -59,6 +103,20 @@ impl<T> GridBlock<T> {
             .flat_map(|row| row.iter())
     }
 
+    /// Returns a new grid with the same dimensions. Every element is the result of `mapper`
+    /// applied on the element at the same position.
+    pub fn map<U, F>(&self, mapper: F) -> GridBlock<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        GridBlock::new(
+            self.grid.map(mapper),
+            self.width,
+            self.height,
+            self.block_dimensions,
+        )
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &[T]>
     where
         T: Clone,
@@ -77,15 +135,61 @@ impl<T> GridBlock<T> {
     }
 }
 
+impl GridBlock<bool> {
+    /// Returns the grid as text with `filled` for set elements and `empty` for all others.
+    /// Useful to see the shape of a glyph in snapshots and logs.
+    pub fn display_with(&self, filled: char, empty: char) -> GlyphDisplay<'_> {
+        GlyphDisplay {
+            grid: self,
+            filled,
+            empty,
+        }
+    }
+}
+
+/// Prints every row of the grid as concatenated elements. Rows are separated by line breaks.
 impl<T> Display for GridBlock<T>
 where
     T: Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.grid)
+        write_rows(f, self, |f, element| write!(f, "{element}"))
     }
 }
 
+/// Text of a grid of set elements created by [`GridBlock::display_with`]
+#[derive(Debug, Clone, Copy)]
+pub struct GlyphDisplay<'a> {
+    grid: &'a GridBlock<bool>,
+    filled: char,
+    empty: char,
+}
+
+impl Display for GlyphDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_rows(f, self.grid, |f, &set| {
+            write!(f, "{}", if set { self.filled } else { self.empty })
+        })
+    }
+}
+
+fn write_rows<T>(
+    f: &mut std::fmt::Formatter<'_>,
+    grid: &GridBlock<T>,
+    mut write_element: impl FnMut(&mut std::fmt::Formatter<'_>, &T) -> std::fmt::Result,
+) -> std::fmt::Result {
+    for (y, row) in grid.grid.rows().into_iter().enumerate() {
+        if y > 0 {
+            writeln!(f)?;
+        }
+        for element in row {
+            write_element(f, element)?;
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod testing {
     use super::*;
@@ -108,6 +212,34 @@ mod testing {
         insta::assert_yaml_snapshot!("1 and 5 row", &[row, third_row]);
     }
 
+    #[test]
+    fn should_map_every_element() {
+        let block = ascii_art_lib::build_1(' ', '*').build();
+
+        let mapped = block.map(|&symbol| symbol == '*');
+
+        assert_eq!((3, 5), (mapped.width(), mapped.height()));
+        assert_eq!(Some(&true), mapped.get(2, 4));
+        assert_eq!(Some(&false), mapped.get(0, 4));
+    }
+
+    #[test]
+    fn should_display_rows_of_elements() {
+        let one = ascii_art_lib::build_1('.', '#').build();
+
+        assert_eq!("..#\n..#\n..#\n..#\n..#", one.to_string());
+    }
+
+    #[test]
+    fn should_display_glyph_with_chars() {
+        let minus = ascii_art_lib::build_minus(false, true).build();
+
+        assert_eq!(
+            "   \n   \n███\n   \n   ",
+            minus.display_with('█', ' ').to_string()
+        );
+    }
+
     #[test]
     fn should_traverse_from_top_left_to_bottom_right() {
         let one = ascii_art_lib::build_1(" ", "*").build();
@@ -116,7 +248,7 @@ mod testing {
         let four = ascii_art_lib::build_4(" ", "*").build();
 
         let actual: Vec<String> =
-            GridBlock::iter_top_left_bottom_right(&vec![one, space, double_point, four], 5)
+            GridBlock::iter_top_left_bottom_right(&[one, space, double_point, four], 5)
                 .map(|line| {
                     line.into_iter()
                         .map(|symbol| symbol.to_string())
@@ -1,4 +1,4 @@
-use super::GridBlock;
+use super::{BlockSize, Dimension, GridBlock, GridBlockError};
 use ndarray::Array2;
 
 /// Builder for constructing a immutable `GridBlock`
@@ -46,8 +46,10 @@ use ndarray::Array2;
 ///     ]
 /// )
 /// ```
+#[derive(Debug, Clone)]
 pub struct BlockGridBuilder<T> {
-    block_size: usize,
+    block_width: usize,
+    block_height: usize,
     blocks_in_x: usize,
     blocks_in_y: usize,
     default_value: T,
@@ -71,22 +73,34 @@ where
     pub fn with_default(default_value: T) -> Self {
         Self::init(default_value)
     }
-    /// How big a block in the grid is.
+    /// How big a block in the grid is. A block will be `new_block_size` elements wide and high.
     /// Grid will be [`block_size`] * [`blocks_in_y`] * [`blocks_in_x`] elements large.
-    ///
-    /// # Panic
-    ///
-    /// If `new_block_size` is zero
+    /// Must not be zero while building.
     pub fn block_size(&mut self, new_block_size: usize) -> &mut Self {
-        if new_block_size < 1 {
-            panic!("{} must be not be zero", stringify!(block_size));
-        }
+        self.block_width(new_block_size)
+            .block_height(new_block_size)
+    }
+
+    /// How many elements a block has in x direction. Must not be zero while building.
+    pub fn block_width(&mut self, new_block_width: usize) -> &mut Self {
+        self.block_width = new_block_width;
+
+        self
+    }
 
-        self.block_size = new_block_size;
+    /// How many elements a block has in y direction. Must not be zero while building.
+    pub fn block_height(&mut self, new_block_height: usize) -> &mut Self {
+        self.block_height = new_block_height;
 
         self
     }
 
+    /// Sets width and height of a block at once. Both must not be zero while building.
+    pub fn block_dimensions(&mut self, dimensions: BlockSize) -> &mut Self {
+        self.block_width(dimensions.width)
+            .block_height(dimensions.height)
+    }
+
     pub fn reset_sectors(&mut self) -> &mut Self {
         self.setting_blocks.clear();
 
@@ -104,63 +118,103 @@ where
         self
     }
 
-    /// # Panic
-    ///
-    /// If [`blocks_in_x`] and [`blocks_in_y`] are both zero after calling this method.
+    /// Number of blocks in x direction. Must not be zero while building.
     pub fn blocks_in_x(&mut self, new_blocks_in_x: usize) -> &mut Self {
-        self.validate_x_y();
-
         self.blocks_in_x = new_blocks_in_x;
 
         self
     }
 
-    /// # Panic
-    ///
-    /// If [`blocks_in_x`] and [`blocks_in_y`] are both zero after calling this method.
+    /// Number of blocks in y direction. Must not be zero while building.
     pub fn blocks_in_y(&mut self, new_blocks_in_y: usize) -> &mut Self {
-        self.validate_x_y();
-
         self.blocks_in_y = new_blocks_in_y;
 
         self
     }
 
+    /// Number of blocks the grid will have in x direction.
+    pub fn width_in_blocks(&self) -> usize {
+        self.blocks_in_x
+    }
+
+    /// Number of blocks the grid will have in y direction.
+    pub fn height_in_blocks(&self) -> usize {
+        self.blocks_in_y
+    }
+
     pub fn set_block_sector(&mut self, x: usize, y: usize, value: T) -> &mut Self {
         self.setting_blocks.push((x, y, value));
         self
     }
 
+    /// Returns a builder with the same configuration. The default value and the values of all set
+    /// sectors are the result of `mapper`.
+    pub fn map<U, F>(&self, mut mapper: F) -> BlockGridBuilder<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        BlockGridBuilder {
+            block_width: self.block_width,
+            block_height: self.block_height,
+            blocks_in_x: self.blocks_in_x,
+            blocks_in_y: self.blocks_in_y,
+            default_value: mapper(&self.default_value),
+            setting_blocks: self
+                .setting_blocks
+                .iter()
+                .map(|(x, y, value)| (*x, *y, mapper(value)))
+                .collect(),
+        }
+    }
+
+    /// Builds grid according to current configuration.
+    ///
+    /// # Panic
+    ///
+    /// If the configuration is invalid. See [`BlockGridBuilder::try_build`] for details.
     pub fn build(&self) -> GridBlock<T> {
-        let block_size = self.block_size;
-        let width = block_size * self.blocks_in_x;
-        let height = block_size * self.blocks_in_y;
+        self.try_build().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Builds grid according to current configuration.
+    ///
+    /// # Errors
+    ///
+    /// - If the block width, block height, blocks in x or blocks in y is zero.
+    /// - If a set sector is outside of the blocks in x and y.
+    pub fn try_build(&self) -> Result<GridBlock<T>, GridBlockError> {
+        self.validate()?;
+
+        let (block_width, block_height) = (self.block_width, self.block_height);
+        let width = block_width * self.blocks_in_x;
+        let height = block_height * self.blocks_in_y;
         let mut build: Array2<T> = Array2::from_elem((height, width), self.default_value.clone());
 
         for (x, y, to_insert) in self.setting_blocks.iter() {
-            let to_add = block_size - 1;
+            let (scaled_x, scaled_y) = (*x * block_width, *y * block_height);
 
-            let (scaled_x, scaled_y) = (*x * block_size, *y * block_size);
-
-            let max_x = scaled_x + to_add;
-            let max_y = scaled_y + to_add;
+            let max_x = scaled_x + block_width - 1;
+            let max_y = scaled_y + block_height - 1;
 
             for next_y in scaled_y..=max_y {
                 for next_x in scaled_x..=max_x {
-                    let insert_into = build
-                        .get_mut((next_y, next_x))
-                        .expect("Unexpected: during insertion of value out of bound indexing");
-                    *insert_into = to_insert.clone();
+                    build[(next_y, next_x)] = to_insert.clone();
                 }
             }
         }
 
-        GridBlock::new(build, width, height)
+        Ok(GridBlock::new(
+            build,
+            width,
+            height,
+            BlockSize::new(block_width, block_height),
+        ))
     }
 
     fn init(value: T) -> Self {
         Self {
-            block_size: 1,
+            block_width: 1,
+            block_height: 1,
             blocks_in_x: 1,
             blocks_in_y: 1,
             setting_blocks: Default::default(),
@@ -168,14 +222,31 @@ where
         }
     }
 
-    fn validate_x_y(&self) {
-        if self.blocks_in_x < 1 && self.blocks_in_y < 1 {
-            panic!(
-                "{} and {} must be not be zero",
-                stringify!(blocks_in_x),
-                stringify!(blocks_in_y)
-            );
+    fn validate(&self) -> Result<(), GridBlockError> {
+        let dimensions = [
+            (self.block_width, Dimension::BlockWidth),
+            (self.block_height, Dimension::BlockHeight),
+            (self.blocks_in_x, Dimension::BlocksInX),
+            (self.blocks_in_y, Dimension::BlocksInY),
+        ];
+        if let Some((_, dimension)) = dimensions.iter().find(|(value, _)| *value < 1) {
+            return Err(GridBlockError::ZeroDimension(*dimension));
+        }
+
+        let out_of_bounds = self
+            .setting_blocks
+            .iter()
+            .find(|(x, y, _)| *x >= self.blocks_in_x || *y >= self.blocks_in_y);
+        if let Some(&(x, y, _)) = out_of_bounds {
+            return Err(GridBlockError::SectorOutOfBounds {
+                x,
+                y,
+                blocks_in_x: self.blocks_in_x,
+                blocks_in_y: self.blocks_in_y,
+            });
         }
+
+        Ok(())
     }
 }
 
@@ -184,7 +255,7 @@ mod testing {
     use super::*;
     #[test]
     fn should_set_sectors() {
-        let block = BlockGridBuilder::with_default(' ')
+        let block = BlockGridBuilder::with_default('.')
             .blocks_in_y(3)
             .blocks_in_x(4)
             .block_size(2)
@@ -197,7 +268,77 @@ mod testing {
             .set_block_sector(0, 1, '*')
             .build();
 
-        insta::assert_display_snapshot!(block);
+        insta::assert_snapshot!(block);
+    }
+
+    #[test]
+    fn should_return_error_for_zero_dimension() {
+        let actual = create_base_nine().blocks_in_y(0).try_build();
+
+        assert_eq!(
+            GridBlockError::ZeroDimension(Dimension::BlocksInY),
+            actual.unwrap_err()
+        );
+
+        let actual = create_base_nine().blocks_in_x(0).try_build();
+
+        assert_eq!(
+            GridBlockError::ZeroDimension(Dimension::BlocksInX),
+            actual.unwrap_err()
+        );
+
+        let actual = create_base_nine().block_size(0).try_build();
+
+        assert_eq!(
+            GridBlockError::ZeroDimension(Dimension::BlockWidth),
+            actual.unwrap_err()
+        );
+
+        let actual = create_base_nine().block_height(0).try_build();
+
+        assert_eq!(
+            GridBlockError::ZeroDimension(Dimension::BlockHeight),
+            actual.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn should_return_error_for_sector_out_of_bounds() {
+        let actual = create_base_nine().set_block_sector(1, 5, '*').try_build();
+
+        assert_eq!(
+            GridBlockError::SectorOutOfBounds {
+                x: 1,
+                y: 5,
+                blocks_in_x: 3,
+                blocks_in_y: 5
+            },
+            actual.unwrap_err()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "block width must not be zero")]
+    fn should_panic_for_zero_block_size() {
+        create_base_nine().block_size(0).build();
+    }
+
+    #[test]
+    #[should_panic(expected = "blocks in x must not be zero")]
+    fn should_panic_for_zero_blocks_while_building() {
+        create_base_nine().blocks_in_x(0).build();
+    }
+
+    #[test]
+    fn should_map_default_and_sectors() {
+        let block = create_base_nine()
+            .block_size(2)
+            .map(|&symbol| symbol == '*')
+            .build();
+
+        assert_eq!((6, 10), (block.width(), block.height()));
+        assert_eq!(Some(&true), block.get(5, 7));
+        assert_eq!(Some(&false), block.get(0, 7));
     }
 
     #[test]
@@ -210,17 +351,34 @@ mod testing {
     fn should_should_produce_nine() {
         let block = create_base_nine().build();
 
-        insta::assert_display_snapshot!(block);
+        insta::assert_snapshot!(block);
     }
     #[test]
     fn should_should_produce_nine_x3() {
         let block = create_base_nine().block_size(3).build();
 
-        insta::assert_display_snapshot!(block);
+        insta::assert_snapshot!(block);
+    }
+
+    #[test]
+    fn should_should_produce_nine_2x1() {
+        let block = create_base_nine().block_width(2).block_height(1).build();
+
+        insta::assert_snapshot!(block);
+    }
+
+    #[test]
+    fn should_produce_same_with_block_dimensions() {
+        let block = create_base_nine()
+            .block_dimensions(BlockSize::new(2, 3))
+            .build();
+
+        assert_eq!((6, 15), (block.width(), block.height()));
+        assert_eq!(BlockSize::new(2, 3), block.block_dimensions());
     }
 
     fn create_base_nine() -> BlockGridBuilder<char> {
-        let mut block = BlockGridBuilder::with_default(' ');
+        let mut block = BlockGridBuilder::with_default('.');
         block
             .blocks_in_y(5)
             .blocks_in_x(3)
@@ -0,0 +1,56 @@
+use std::error::Error;
+use std::fmt::Display;
+
+/// Dimension of a grid which can be configured in a [`super::BlockGridBuilder`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    BlockWidth,
+    BlockHeight,
+    BlocksInX,
+    BlocksInY,
+}
+
+impl Display for Dimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::BlockWidth => "block width",
+            Self::BlockHeight => "block height",
+            Self::BlocksInX => "blocks in x",
+            Self::BlocksInY => "blocks in y",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Reasons why a [`super::BlockGridBuilder`] could not build a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridBlockError {
+    /// The contained dimension was configured as zero.
+    ZeroDimension(Dimension),
+    /// A sector was set outside of the grid.
+    SectorOutOfBounds {
+        x: usize,
+        y: usize,
+        blocks_in_x: usize,
+        blocks_in_y: usize,
+    },
+}
+
+impl Display for GridBlockError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ZeroDimension(dimension) => write!(f, "{dimension} must not be zero"),
+            Self::SectorOutOfBounds {
+                x,
+                y,
+                blocks_in_x,
+                blocks_in_y,
+            } => write!(
+                f,
+                "Sector ({x}, {y}) is outside of grid with {blocks_in_x} * {blocks_in_y} blocks"
+            ),
+        }
+    }
+}
+
+impl Error for GridBlockError {}
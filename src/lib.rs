@@ -1,10 +1,31 @@
 //! API to generate numbers and letters in blocks to display them in tui environment
 //! which does not support font sizes by nature
+//!
+//! The module `tui_block` and the reexports of tui rs need the default feature `tui`.
+//! The module `ratatui_block` with the same API for ratatui needs the feature `ratatui`.
+//! Both bind the generic module `span_block` to the types of their library.
+//! The module `crossterm_render` which writes grids without a tui frame needs the feature
+//! `crossterm`.
+//! Without it only the generic grids and glyphs are available.
 
+pub mod ansi;
 pub mod ascii_art_lib;
+pub mod braille;
+#[cfg(feature = "crossterm")]
+pub mod crossterm_render;
+pub mod font;
 pub mod grid_block;
+#[cfg(feature = "ratatui")]
+pub mod ratatui_block;
+pub mod seven_segment;
+#[cfg(any(feature = "tui", feature = "ratatui"))]
+pub mod span_block;
+pub mod styled_cell;
+pub mod time_format;
+#[cfg(feature = "tui")]
 pub mod tui_block;
 
+#[cfg(feature = "tui")]
 pub use tui::{
     style::Color,
     text::{Span, Spans},
@@ -0,0 +1,33 @@
+//! Blocks as spans of ratatui with the same API as [`crate::tui_block`].
+//! A line of blocks is a ratatui [`Line`] which is named `Spans` in this API like in tui rs.
+//!
+//! # Example
+//! ```
+//! use block_builder::ratatui_block::{Color, LineBlockBuilder};
+//! use ratatui::{buffer::Buffer, layout::{Alignment, Rect}, widgets::Widget};
+//!
+//! let area = Rect::new(0, 0, 9, 5);
+//! let mut buffer = Buffer::empty(area);
+//! LineBlockBuilder::new(1, Color::White, Color::Black)
+//!     .number(42)
+//!     .build_widget()
+//!     .alignment(Alignment::Center)
+//!     .render(area, &mut buffer);
+//! ```
+
+pub use ratatui::{
+    style::Color,
+    text::{Line, Span},
+};
+
+use crate::span_block;
+
+// Every generic function of the shared module infers ratatui from its colour arguments.
+pub use crate::span_block::*;
+
+span_block::bind_backend!(
+    ratatui,
+    line: Line,
+    spans: spans,
+    cell: |buf, x, y| buf[(x, y)],
+);
@@ -0,0 +1,235 @@
+//! Glyphs built from the segments of a seven segment display. Every segment can be lit on its own
+//! which allows partial states and the hexadecimal digits A to F.
+//!
+//! ```text
+//!  aa
+//! f  b
+//! f  b
+//!  gg
+//! e  c
+//! e  c
+//!  dd p
+//! ```
+
+use std::ops::{BitOr, BitOrAssign};
+
+use crate::grid_block::BlockGridBuilder;
+
+/// Number of blocks a seven segment glyph has in x direction including the decimal point
+pub const WIDTH: usize = 5;
+/// Number of blocks a seven segment glyph has in y direction
+pub const HEIGHT: usize = 7;
+
+/// Set of lit segments of one digit on a seven segment display.
+/// Segments are combined via `|` like `Segments::B | Segments::C` for 1.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Segments(u8);
+
+impl Segments {
+    pub const NONE: Self = Self(0);
+    /// Top
+    pub const A: Self = Self(1);
+    /// Upper right
+    pub const B: Self = Self(1 << 1);
+    /// Lower right
+    pub const C: Self = Self(1 << 2);
+    /// Bottom
+    pub const D: Self = Self(1 << 3);
+    /// Lower left
+    pub const E: Self = Self(1 << 4);
+    /// Upper left
+    pub const F: Self = Self(1 << 5);
+    /// Middle
+    pub const G: Self = Self(1 << 6);
+    /// Decimal point right of the bottom
+    pub const DP: Self = Self(1 << 7);
+    pub const ALL: Self = Self(u8::MAX);
+
+    /// Returns true if every segment of `other` is lit in `self`.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the lit segments for a digit, the hexadecimal digits A to F in any case,
+    /// `-` or white space.
+    /// Returns none for other characters.
+    pub fn from_char(symbol: char) -> Option<Self> {
+        let (a, b, c, d, e, f, g) = (
+            Self::A,
+            Self::B,
+            Self::C,
+            Self::D,
+            Self::E,
+            Self::F,
+            Self::G,
+        );
+        let segments = match symbol.to_ascii_uppercase() {
+            '0' => a | b | c | d | e | f,
+            '1' => b | c,
+            '2' => a | b | g | e | d,
+            '3' => a | b | g | c | d,
+            '4' => f | g | b | c,
+            '5' => a | f | g | c | d,
+            '6' => a | f | g | e | d | c,
+            '7' => a | b | c,
+            '8' => a | b | c | d | e | f | g,
+            '9' => a | b | c | d | f | g,
+            'A' => a | b | c | e | f | g,
+            'B' => c | d | e | f | g,
+            'C' => a | d | e | f,
+            'D' => b | c | d | e | g,
+            'E' => a | d | e | f | g,
+            'F' => a | e | f | g,
+            '-' => g,
+            ' ' => Self::NONE,
+            _ => return None,
+        };
+
+        Some(segments)
+    }
+
+    /// Returns the character which [`Segments::from_char`] maps to these segments ignoring the
+    /// decimal point. Returns none for segments which do not form a known character.
+    pub fn to_char(self) -> Option<char> {
+        let without_point = Self(self.0 & !Self::DP.0);
+        "0123456789ABCDEF- "
+            .chars()
+            .find(|&symbol| Self::from_char(symbol) == Some(without_point))
+    }
+}
+
+impl BitOr for Segments {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Segments {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Sectors of every segment within a glyph of [`WIDTH`] * [`HEIGHT`] blocks
+const SEGMENT_SECTORS: [(Segments, &[(usize, usize)]); 8] = [
+    (Segments::A, &[(1, 0), (2, 0)]),
+    (Segments::B, &[(3, 1), (3, 2)]),
+    (Segments::C, &[(3, 4), (3, 5)]),
+    (Segments::D, &[(1, 6), (2, 6)]),
+    (Segments::E, &[(0, 4), (0, 5)]),
+    (Segments::F, &[(0, 1), (0, 2)]),
+    (Segments::G, &[(1, 3), (2, 3)]),
+    (Segments::DP, &[(4, 6)]),
+];
+
+/// Builds a glyph of [`WIDTH`] * [`HEIGHT`] blocks.
+/// Lit segments have the value `lit_v` and unlit segments `ghost_v` like the faint segments of a
+/// real display. Elements between segments have the value `default_v`.
+/// Pass `default_v` as `ghost_v` to hide unlit segments.
+pub fn build_segments<T>(
+    segments: Segments,
+    default_v: T,
+    ghost_v: T,
+    lit_v: T,
+) -> BlockGridBuilder<T>
+where
+    T: Clone,
+{
+    let mut builder = BlockGridBuilder::with_default(default_v);
+    builder.blocks_in_x(WIDTH).blocks_in_y(HEIGHT);
+
+    for (segment, sectors) in SEGMENT_SECTORS {
+        let value = if segments.contains(segment) {
+            lit_v.clone()
+        } else {
+            ghost_v.clone()
+        };
+        builder.set_bulk_sectors(value, sectors);
+    }
+
+    builder
+}
+
+/// Builds a separator of 2 * [`HEIGHT`] blocks which fits between seven segment glyphs.
+/// The left column has the dots of `:` or the point of `.` with the value `lit_v`. The right
+/// column stays empty like the column of the decimal point of a glyph.
+/// Returns none for other characters.
+pub fn build_separator<T>(symbol: char, default_v: T, lit_v: T) -> Option<BlockGridBuilder<T>>
+where
+    T: Clone,
+{
+    let sectors: &[(usize, usize)] = match symbol {
+        ':' => &[(0, 2), (0, 4)],
+        '.' => &[(0, HEIGHT - 1)],
+        _ => return None,
+    };
+
+    let mut builder = BlockGridBuilder::with_default(default_v);
+    builder
+        .blocks_in_x(2)
+        .blocks_in_y(HEIGHT)
+        .set_bulk_sectors(lit_v, sectors);
+
+    Some(builder)
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    fn rows_of(builder: &BlockGridBuilder<char>) -> Vec<String> {
+        builder
+            .build()
+            .iter()
+            .map(|row| row.iter().collect())
+            .collect()
+    }
+
+    #[test]
+    fn should_light_only_given_segments() {
+        let actual = build_segments(Segments::B | Segments::C | Segments::DP, ' ', '.', '#');
+
+        assert_eq!(
+            vec![" ..  ", ".  # ", ".  # ", " ..  ", ".  # ", ".  # ", " .. #"],
+            rows_of(&actual)
+        );
+    }
+
+    #[test]
+    fn should_hide_unlit_segments_with_default_as_ghost() {
+        let actual = build_segments(Segments::from_char('F').unwrap(), ' ', ' ', '#');
+
+        assert_eq!(
+            vec![" ##  ", "#    ", "#    ", " ##  ", "#    ", "#    ", "     "],
+            rows_of(&actual)
+        );
+    }
+
+    #[test]
+    fn should_build_separators_as_high_as_glyphs() {
+        let colon = build_separator(':', ' ', '#').unwrap();
+        let point = build_separator('.', ' ', '#').unwrap();
+
+        assert_eq!(
+            vec!["  ", "  ", "# ", "  ", "# ", "  ", "  "],
+            rows_of(&colon)
+        );
+        assert_eq!(
+            vec!["  ", "  ", "  ", "  ", "  ", "  ", "# "],
+            rows_of(&point)
+        );
+        assert!(build_separator('-', ' ', '#').is_none());
+    }
+
+    #[test]
+    fn should_map_between_chars_and_segments() {
+        assert_eq!(Some(Segments::B | Segments::C), Segments::from_char('1'));
+        assert_eq!(Segments::from_char('b'), Segments::from_char('B'));
+        assert_eq!(None, Segments::from_char('x'));
+        assert_eq!(Some('8'), Segments::ALL.to_char());
+        assert_eq!(Some('-'), (Segments::G | Segments::DP).to_char());
+        assert_eq!(None, (Segments::A | Segments::D).to_char());
+    }
+}
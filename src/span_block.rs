@@ -0,0 +1,579 @@
+//! Blocks as spans of a tui library. Shared by [`crate::tui_block`] and
+//! [`crate::ratatui_block`] which bind it to the types of tui rs or ratatui.
+//!
+//! Everything is generic over the colour type of the library. The colour type knows the span,
+//! line and widget types of its library via [`BlockColor`].
+
+mod big_text;
+mod line_block_builder;
+
+pub use big_text::BigText;
+pub use line_block_builder::{
+    Base, BitGrouping, FitMode, Grouping, LineBlockBuilder, LineBlockError, Pad, Radix,
+};
+
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+use crate::grid_block::BlockGridBuilder;
+use crate::seven_segment::{self, Segments};
+use crate::styled_cell::{CellColor, StyledCell};
+use crate::{ascii_art_lib, braille, grid_block::GridBlock};
+
+/// Colour type of a tui library. Binds the types of the library which blocks are built from
+/// and rendered into.
+pub trait BlockColor: Copy + PartialEq + Debug {
+    /// Span of one element of a block
+    type Span: Clone + Debug;
+    /// Row of spans
+    type Line;
+    type Rect: Copy;
+    type Alignment: Copy + Debug;
+    /// Border and title around a widget
+    type Block<'a>: Clone + Debug;
+    type Buffer;
+
+    /// Returns `content` with `fg` and `bg` as colours. None is the colour of the terminal.
+    fn span(
+        content: impl Into<Cow<'static, str>>,
+        fg: Option<Self>,
+        bg: Option<Self>,
+    ) -> Self::Span;
+
+    /// Returns content, foreground and background colour of `span`.
+    fn parts_of(span: &Self::Span) -> (&str, Option<Self>, Option<Self>);
+
+    fn line(spans: Vec<Self::Span>) -> Self::Line;
+
+    fn spans_of(line: &Self::Line) -> &[Self::Span];
+
+    /// Returns none for the default colour of the terminal.
+    fn to_cell_color(self) -> Option<CellColor>;
+
+    /// Returns left, top, width and height of `rect`.
+    fn bounds_of(rect: Self::Rect) -> (u16, u16, u16, u16);
+
+    /// Returns by how many cells content is moved to the right if `free_width` cells are left
+    /// over.
+    fn offset_of(alignment: Self::Alignment, free_width: u16) -> u16;
+
+    /// Renders `block` into `area` of `buf` and returns the area within the border.
+    fn render_block(block: Self::Block<'_>, area: Self::Rect, buf: &mut Self::Buffer)
+        -> Self::Rect;
+
+    /// Sets symbol and style of the cell at `x` and `y` to the ones of `span`.
+    fn set_cell(buf: &mut Self::Buffer, x: u16, y: u16, span: &Self::Span);
+}
+
+macro_rules! tui_block {
+    ($fn_name:ident, $builder_name:ident) => {
+        pub fn $fn_name<C: BlockColor>(default_bg: C, bg: C) -> BlockGridBuilder<C::Span> {
+            ascii_art_lib::$builder_name(element(default_bg), element(bg))
+        }
+    };
+}
+
+/// Binds this module to the colour, span, line and widget types of the tui library `$lib`.
+/// - `$line`: Name of the type of a row of spans in the `text` module of `$lib`
+/// - `$spans`: Field of `$line` with its spans
+/// - `$cell`: Returns the cell of buffer `$buf` at `$x` and `$y`
+macro_rules! bind_backend {
+    (
+        $lib:ident,
+        line: $line:ident,
+        spans: $spans:tt,
+        cell: |$buf:ident, $x:ident, $y:ident| $cell:expr $(,)?
+    ) => {
+        pub type LineBlockBuilder = $crate::span_block::LineBlockBuilder<$lib::style::Color>;
+        pub type BigText<'a> = $crate::span_block::BigText<'a, $lib::style::Color>;
+
+        /// **Panics** if blocks in slice `to_clue` do not have the same height
+        pub fn build_tui_line_block(
+            to_clue: &[$crate::grid_block::GridBlock<$lib::text::Span<'static>>],
+        ) -> Vec<$lib::text::$line<'static>> {
+            $crate::span_block::build_line_block::<$lib::style::Color>(to_clue)
+        }
+
+        /// Converts a span of a block into a styled cell, for example to write it via
+        /// [`crate::ansi`] without a tui frame. Only the first character of the span is kept.
+        pub fn to_styled_cell(span: &$lib::text::Span<'static>) -> $crate::styled_cell::StyledCell {
+            $crate::span_block::span_to_styled_cell::<$lib::style::Color>(span)
+        }
+
+        impl $lib::widgets::Widget for BigText<'_> {
+            fn render(self, area: $lib::layout::Rect, buf: &mut $lib::buffer::Buffer) {
+                self.render_into(area, buf);
+            }
+        }
+
+        impl $crate::span_block::BlockColor for $lib::style::Color {
+            type Span = $lib::text::Span<'static>;
+            type Line = $lib::text::$line<'static>;
+            type Rect = $lib::layout::Rect;
+            type Alignment = $lib::layout::Alignment;
+            type Block<'a> = $lib::widgets::Block<'a>;
+            type Buffer = $lib::buffer::Buffer;
+
+            fn span(
+                content: impl Into<std::borrow::Cow<'static, str>>,
+                fg: Option<Self>,
+                bg: Option<Self>,
+            ) -> Self::Span {
+                let style = $lib::style::Style {
+                    fg,
+                    bg,
+                    ..$lib::style::Style::default()
+                };
+                $lib::text::Span::styled(content, style)
+            }
+
+            fn parts_of(span: &Self::Span) -> (&str, Option<Self>, Option<Self>) {
+                (&span.content, span.style.fg, span.style.bg)
+            }
+
+            fn line(spans: Vec<Self::Span>) -> Self::Line {
+                $lib::text::$line::from(spans)
+            }
+
+            fn spans_of(line: &Self::Line) -> &[Self::Span] {
+                &line.$spans
+            }
+
+            fn to_cell_color(self) -> Option<$crate::styled_cell::CellColor> {
+                use $crate::styled_cell::CellColor;
+                use $lib::style::Color;
+
+                let color = match self {
+                    Color::Reset => return None,
+                    Color::Black => CellColor::BLACK,
+                    Color::Red => CellColor::RED,
+                    Color::Green => CellColor::GREEN,
+                    Color::Yellow => CellColor::YELLOW,
+                    Color::Blue => CellColor::BLUE,
+                    Color::Magenta => CellColor::MAGENTA,
+                    Color::Cyan => CellColor::CYAN,
+                    Color::Gray => CellColor::GRAY,
+                    Color::DarkGray => CellColor::DARK_GRAY,
+                    Color::LightRed => CellColor::Indexed(9),
+                    Color::LightGreen => CellColor::Indexed(10),
+                    Color::LightYellow => CellColor::Indexed(11),
+                    Color::LightBlue => CellColor::Indexed(12),
+                    Color::LightMagenta => CellColor::Indexed(13),
+                    Color::LightCyan => CellColor::Indexed(14),
+                    Color::White => CellColor::WHITE,
+                    Color::Rgb(r, g, b) => CellColor::Rgb(r, g, b),
+                    Color::Indexed(index) => CellColor::Indexed(index),
+                };
+
+                Some(color)
+            }
+
+            fn bounds_of(rect: Self::Rect) -> (u16, u16, u16, u16) {
+                (rect.x, rect.y, rect.width, rect.height)
+            }
+
+            fn offset_of(alignment: Self::Alignment, free_width: u16) -> u16 {
+                match alignment {
+                    $lib::layout::Alignment::Left => 0,
+                    $lib::layout::Alignment::Center => free_width / 2,
+                    $lib::layout::Alignment::Right => free_width,
+                }
+            }
+
+            fn render_block(
+                block: Self::Block<'_>,
+                area: Self::Rect,
+                buf: &mut Self::Buffer,
+            ) -> Self::Rect {
+                use $lib::widgets::Widget;
+
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+
+            fn set_cell($buf: &mut Self::Buffer, $x: u16, $y: u16, span: &Self::Span) {
+                $cell.set_symbol(&span.content).set_style(span.style);
+            }
+        }
+    };
+}
+pub(crate) use bind_backend;
+
+/// White space with the background `bg` as element of a block
+fn element<C: BlockColor>(bg: C) -> C::Span {
+    C::span(" ", None, Some(bg))
+}
+
+/// **Panics** if blocks in slice `to_clue` do not have the same height
+pub(crate) fn build_line_block<C: BlockColor>(to_clue: &[GridBlock<C::Span>]) -> Vec<C::Line> {
+    let max = to_clue
+        .iter()
+        .map(|block| block.height())
+        .max_by_key(|&block| block)
+        .unwrap_or(0);
+    let mut all_spans: Vec<C::Line> = Vec::with_capacity(max);
+
+    for row in GridBlock::iter_top_left_bottom_right(to_clue, max) {
+        let mut spans = Vec::new();
+        for symbol in row {
+            spans.push(symbol.clone());
+        }
+
+        all_spans.push(C::line(spans));
+    }
+
+    all_spans
+}
+
+/// Like [`crate::tui_block::build_tui_line_block`] but 2 vertically stacked elements are put
+/// into one terminal cell via the half block characters ▀, ▄ and █. This halves the number of
+/// rows needed.
+/// The colour of an element is the background colour of its span.
+/// Elements with the colour `default_bg` are regarded as empty.
+///
+/// **Panics** if blocks in slice `to_clue` do not have the same height
+pub fn build_tui_half_block_line<C: BlockColor>(
+    to_clue: &[GridBlock<C::Span>],
+    default_bg: C,
+) -> Vec<C::Line> {
+    let to_color = |symbol: &C::Span| C::parts_of(symbol).2.unwrap_or(default_bg);
+    let full_lines = build_line_block::<C>(to_clue);
+
+    full_lines
+        .chunks(2)
+        .map(|pair| {
+            let upper = C::spans_of(&pair[0]).iter().map(to_color);
+            let lower = pair
+                .get(1)
+                .map(|line| C::spans_of(line).iter().map(to_color).collect())
+                .unwrap_or_else(|| vec![default_bg; C::spans_of(&pair[0]).len()]);
+
+            let spans: Vec<C::Span> = upper
+                .zip(lower)
+                .map(|(upper, lower)| half_block_span(upper, lower, default_bg))
+                .collect();
+            C::line(spans)
+        })
+        .collect()
+}
+
+/// Renders `grid` as braille characters with `fg` as colour of set dots.
+/// Every terminal cell contains 2 * 4 elements of `grid`. See [`braille::to_braille_rows`] for
+/// details.
+pub fn build_tui_braille<C: BlockColor>(grid: &GridBlock<bool>, fg: C, bg: C) -> Vec<C::Line> {
+    braille::to_braille_rows(grid)
+        .into_iter()
+        .map(|row| C::line(vec![C::span(row, Some(fg), Some(bg))]))
+        .collect()
+}
+
+/// Shared body of `to_styled_cell` of every backend
+pub(crate) fn span_to_styled_cell<C: BlockColor>(span: &C::Span) -> StyledCell {
+    let (content, fg, bg) = C::parts_of(span);
+    StyledCell::new(
+        content.chars().next().unwrap_or(' '),
+        fg.and_then(C::to_cell_color),
+        bg.and_then(C::to_cell_color),
+    )
+}
+
+fn half_block_span<C: BlockColor>(upper: C, lower: C, default_bg: C) -> C::Span {
+    const UPPER_HALF: &str = "▀";
+    const LOWER_HALF: &str = "▄";
+    const FULL: &str = "█";
+
+    let on_default = |fg| C::span(FULL, Some(fg), Some(default_bg));
+    if upper == lower {
+        if upper == default_bg {
+            element(default_bg)
+        } else {
+            on_default(upper)
+        }
+    } else if lower == default_bg {
+        C::span(UPPER_HALF, Some(upper), Some(default_bg))
+    } else if upper == default_bg {
+        C::span(LOWER_HALF, Some(lower), Some(default_bg))
+    } else {
+        C::span(UPPER_HALF, Some(upper), Some(lower))
+    }
+}
+
+pub fn create_tui_block_space<C: BlockColor>(default_bg: C) -> BlockGridBuilder<C::Span> {
+    ascii_art_lib::build_space(element(default_bg))
+}
+
+pub fn create_tui_block_space_of_size<C: BlockColor>(
+    default_bg: C,
+    width: usize,
+    height: usize,
+) -> BlockGridBuilder<C::Span> {
+    ascii_art_lib::build_space_of_size(element(default_bg), width, height)
+}
+
+/// Comma of 1 * `height` blocks to group digits
+pub fn create_tui_block_small_comma<C: BlockColor>(
+    default_bg: C,
+    bg: C,
+    height: usize,
+) -> BlockGridBuilder<C::Span> {
+    ascii_art_lib::build_small_comma(element(default_bg), element(bg), height)
+}
+
+/// Point of 1 * `height` blocks to group digits
+pub fn create_tui_block_small_point<C: BlockColor>(
+    default_bg: C,
+    bg: C,
+    height: usize,
+) -> BlockGridBuilder<C::Span> {
+    ascii_art_lib::build_small_point(element(default_bg), element(bg), height)
+}
+
+/// Set elements of `glyph` have the background `bg` and all other `default_bg`.
+pub fn create_tui_block_from_glyph<C: BlockColor>(
+    glyph: &BlockGridBuilder<bool>,
+    default_bg: C,
+    bg: C,
+) -> BlockGridBuilder<C::Span> {
+    glyph.map(|&taken| element(if taken { bg } else { default_bg }))
+}
+
+/// Lit segments have the background `bg`, unlit segments `ghost_bg` and the rest `default_bg`.
+/// See [`seven_segment::build_segments`] for details.
+pub fn create_tui_block_segments<C: BlockColor>(
+    segments: Segments,
+    default_bg: C,
+    ghost_bg: C,
+    bg: C,
+) -> BlockGridBuilder<C::Span> {
+    seven_segment::build_segments(
+        segments,
+        element(default_bg),
+        element(ghost_bg),
+        element(bg),
+    )
+}
+
+tui_block! {create_tui_block_1, build_1}
+tui_block! {create_tui_block_2, build_2}
+tui_block! {create_tui_block_3, build_3}
+tui_block! {create_tui_block_4, build_4}
+tui_block! {create_tui_block_5, build_5}
+tui_block! {create_tui_block_6, build_6}
+tui_block! {create_tui_block_7, build_7}
+tui_block! {create_tui_block_8, build_8}
+tui_block! {create_tui_block_9, build_9}
+tui_block! {create_tui_block_0, build_0}
+tui_block! {create_tui_block_double_point, build_double_point}
+tui_block! {create_tui_block_minus, build_minus}
+tui_block! {create_tui_block_plus, build_plus}
+tui_block! {create_tui_block_decimal_point, build_decimal_point}
+tui_block! {create_tui_block_a, build_a}
+tui_block! {create_tui_block_b, build_b}
+tui_block! {create_tui_block_c, build_c}
+tui_block! {create_tui_block_d, build_d}
+tui_block! {create_tui_block_e, build_e}
+tui_block! {create_tui_block_f, build_f}
+tui_block! {create_tui_block_g, build_g}
+tui_block! {create_tui_block_h, build_h}
+tui_block! {create_tui_block_i, build_i}
+tui_block! {create_tui_block_j, build_j}
+tui_block! {create_tui_block_k, build_k}
+tui_block! {create_tui_block_l, build_l}
+tui_block! {create_tui_block_m, build_m}
+tui_block! {create_tui_block_n, build_n}
+tui_block! {create_tui_block_o, build_o}
+tui_block! {create_tui_block_p, build_p}
+tui_block! {create_tui_block_q, build_q}
+tui_block! {create_tui_block_r, build_r}
+tui_block! {create_tui_block_s, build_s}
+tui_block! {create_tui_block_t, build_t}
+tui_block! {create_tui_block_u, build_u}
+tui_block! {create_tui_block_v, build_v}
+tui_block! {create_tui_block_w, build_w}
+tui_block! {create_tui_block_x, build_x}
+tui_block! {create_tui_block_y, build_y}
+tui_block! {create_tui_block_z, build_z}
+
+/// Types of the tui library the tests of the shared code run with. Snapshot tests run with
+/// every enabled library.
+#[cfg(test)]
+mod test_backend {
+    #[cfg(feature = "tui")]
+    pub use tui::{
+        buffer::{Buffer, Cell},
+        layout::{Alignment, Rect},
+        style::{Color, Style},
+        text::{Span, Spans},
+        widgets::{Block, Borders, Widget},
+    };
+
+    #[cfg(not(feature = "tui"))]
+    pub use ratatui::{
+        buffer::{Buffer, Cell},
+        layout::{Alignment, Rect},
+        style::{Color, Style},
+        text::{Line as Spans, Span},
+        widgets::{Block, Borders, Widget},
+    };
+
+    #[cfg(feature = "tui")]
+    pub fn cell(buf: &Buffer, x: u16, y: u16) -> &Cell {
+        buf.get(x, y)
+    }
+
+    #[cfg(not(feature = "tui"))]
+    pub fn cell(buf: &Buffer, x: u16, y: u16) -> &Cell {
+        &buf[(x, y)]
+    }
+
+    #[cfg(feature = "tui")]
+    pub fn symbol_of(cell: &Cell) -> &str {
+        &cell.symbol
+    }
+
+    #[cfg(not(feature = "tui"))]
+    pub fn symbol_of(cell: &Cell) -> &str {
+        cell.symbol()
+    }
+
+    pub fn spans_of<'a>(line: &'a Spans<'static>) -> &'a [Span<'static>] {
+        <Color as super::BlockColor>::spans_of(line)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::test_backend::{spans_of, Color, Span, Style};
+    use super::*;
+    use crate::font::Font;
+
+    #[test]
+    fn should_build_half_block_line() {
+        let zero = create_tui_block_0(Color::Black, Color::White).build();
+        let space = create_tui_block_space(Color::Black).build();
+        let one = create_tui_block_1(Color::Black, Color::White).build();
+
+        let actual = build_tui_half_block_line(&[zero, space, one], Color::Black);
+
+        let symbols: Vec<String> = actual
+            .iter()
+            .map(|line| {
+                spans_of(line)
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(vec!["█▀█   █", "█ █   █", "▀▀▀   ▀"], symbols);
+        assert_eq!(
+            Style::default().fg(Color::White).bg(Color::Black),
+            spans_of(&actual[0])[1].style
+        );
+    }
+
+    #[test]
+    fn should_build_braille_from_any_builder() {
+        let grid = create_tui_block_4(Color::Black, Color::White)
+            .block_size(2)
+            .build()
+            .map(|symbol| symbol.style.bg == Some(Color::White));
+
+        let actual = build_tui_braille(&grid, Color::White, Color::Black);
+
+        assert_eq!(3, actual.len());
+        assert!(actual.iter().all(|line| line.width() == 3));
+    }
+
+    #[test]
+    fn should_use_both_colors_without_default() {
+        let actual = half_block_span(Color::Red, Color::Blue, Color::Black);
+
+        assert_eq!(
+            Span::styled("▀", Style::default().fg(Color::Red).bg(Color::Blue)),
+            actual
+        );
+    }
+
+    #[test]
+    fn should_convert_span_to_styled_cell() {
+        let span = Span::styled("#", Style::default().fg(Color::LightRed));
+
+        let actual = span_to_styled_cell::<Color>(&span);
+
+        assert_eq!(
+            StyledCell::new('#', Some(CellColor::Indexed(9)), None),
+            actual
+        );
+    }
+
+    /// Spans of blocks are # for the colour `taken`, . for `default` and + for others.
+    /// Spans with another content than white space are written as is.
+    fn render_to_text<C: BlockColor>(lines: &[C::Line], taken: C, default: C) -> String {
+        lines
+            .iter()
+            .map(|line| {
+                C::spans_of(line)
+                    .iter()
+                    .map(|span| match C::parts_of(span) {
+                        (" ", _, bg) if bg == Some(taken) => "#".to_string(),
+                        (" ", _, bg) if bg == Some(default) => ".".to_string(),
+                        (" ", _, _) => "+".to_string(),
+                        (other, _, _) => other.to_string(),
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Every backend is checked against the same snapshots
+    fn assert_backend_snapshots<C: BlockColor>(white: C, black: C, dark_gray: C) {
+        let line = LineBlockBuilder::new((2, 1), white, black)
+            .text("12:5A")
+            .expect("Characters should have blocks")
+            .build_line();
+        let half_block_line = LineBlockBuilder::new(1, white, black)
+            .font(Font::bold())
+            .number_grouped(1234, Grouping::Comma)
+            .expect("Digits should have glyphs")
+            .build_half_block_line();
+        let grid = create_tui_block_8(black, white)
+            .block_size(2)
+            .build()
+            .map(|symbol| C::parts_of(symbol).2 == Some(white));
+        let braille = build_tui_braille(&grid, white, black);
+        let seven_segment = LineBlockBuilder::new(1, white, black)
+            .ghost(dark_gray)
+            .seven_segment("0.F")
+            .expect("Characters should have seven segment glyphs")
+            .build_line();
+
+        insta::with_settings!({prepend_module_to_snapshot => false}, {
+            for (name, lines) in [
+                ("backend_line", line),
+                ("backend_half_block_line", half_block_line),
+                ("backend_braille", braille),
+                ("backend_seven_segment", seven_segment),
+            ] {
+                insta::assert_snapshot!(name, render_to_text(&lines, white, black));
+            }
+        });
+    }
+
+    #[cfg(feature = "tui")]
+    #[test]
+    fn should_render_same_lines_with_tui() {
+        use tui::style::Color;
+
+        assert_backend_snapshots(Color::White, Color::Black, Color::DarkGray);
+    }
+
+    #[cfg(feature = "ratatui")]
+    #[test]
+    fn should_render_same_lines_with_ratatui() {
+        use ratatui::style::Color;
+
+        assert_backend_snapshots(Color::White, Color::Black, Color::DarkGray);
+    }
+}
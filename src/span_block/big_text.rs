@@ -0,0 +1,158 @@
+use super::BlockColor;
+use crate::grid_block::GridBlock;
+
+/// Widget which renders blocks side by side directly into the buffer of a frame.
+/// Everything beyond the given area is cut off.
+#[derive(Debug, Clone)]
+pub struct BigText<'a, C: BlockColor> {
+    blocks: Vec<GridBlock<C::Span>>,
+    alignment: Option<C::Alignment>,
+    block: Option<C::Block<'a>>,
+}
+
+impl<'a, C: BlockColor> BigText<'a, C> {
+    /// Blocks in `blocks` are rendered from left to right.
+    ///
+    /// **Panics** during rendering if blocks in `blocks` do not have the same height.
+    pub fn new(blocks: Vec<GridBlock<C::Span>>) -> Self {
+        Self {
+            blocks,
+            alignment: None,
+            block: None,
+        }
+    }
+
+    /// Horizontal alignment of the blocks within the area. Default is left.
+    pub fn alignment(mut self, alignment: C::Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Border and title around the blocks. The blocks are rendered within the inner area.
+    pub fn block(mut self, block: C::Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Number of terminal cells the blocks need in x direction.
+    pub fn width(&self) -> usize {
+        self.blocks.iter().map(|block| block.width()).sum()
+    }
+
+    /// Number of terminal cells the blocks need in y direction.
+    pub fn height(&self) -> usize {
+        self.blocks
+            .iter()
+            .map(|block| block.height())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Implementation of the widget trait of every tui library
+    pub(crate) fn render_into(mut self, area: C::Rect, buf: &mut C::Buffer) {
+        let text_area = match self.block.take() {
+            Some(block) => C::render_block(block, area, buf),
+            None => area,
+        };
+        let (left, top, width, height) = C::bounds_of(text_area);
+
+        let available_width = usize::from(width);
+        let free_width = available_width.saturating_sub(self.width());
+        let offset_x = self.alignment.map_or(0, |alignment| {
+            C::offset_of(alignment, u16::try_from(free_width).unwrap_or(u16::MAX))
+        });
+
+        let rows = GridBlock::iter_top_left_bottom_right(&self.blocks, self.height())
+            .take(usize::from(height));
+        for (y, row) in (top..).zip(rows) {
+            let visible = row
+                .into_iter()
+                .take(available_width.saturating_sub(usize::from(offset_x)));
+            for (x, symbol) in (left + offset_x..).zip(visible) {
+                C::set_cell(buf, x, y, symbol);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::super::test_backend::{
+        cell, symbol_of, Alignment, Block, Borders, Buffer, Color, Rect, Widget,
+    };
+    use super::super::LineBlockBuilder;
+    use super::*;
+
+    fn render_symbols(buffer: &Buffer) -> Vec<String> {
+        buffer
+            .content()
+            .chunks(usize::from(buffer.area.width))
+            .map(|row| row.iter().map(symbol_of).collect())
+            .collect()
+    }
+
+    fn create_widget() -> BigText<'static, Color> {
+        LineBlockBuilder::new(1, Color::White, Color::Black)
+            .letter_o()
+            .space()
+            .letter_k()
+            .build_widget()
+    }
+
+    #[test]
+    fn should_write_into_buffer() {
+        let area = Rect::new(0, 0, 7, 5);
+        let mut buffer = Buffer::empty(area);
+
+        create_widget().render(area, &mut buffer);
+
+        assert_eq!(Color::White, cell(&buffer, 0, 0).bg);
+        assert_eq!(Color::Black, cell(&buffer, 1, 1).bg);
+        assert_eq!(Color::Black, cell(&buffer, 3, 0).bg);
+        assert_eq!(Color::White, cell(&buffer, 6, 4).bg);
+    }
+
+    #[test]
+    fn should_align_right() {
+        let area = Rect::new(0, 0, 10, 5);
+        let mut buffer = Buffer::empty(area);
+
+        create_widget()
+            .alignment(Alignment::Right)
+            .render(area, &mut buffer);
+
+        assert_eq!(Color::Reset, cell(&buffer, 2, 0).bg);
+        assert_eq!(Color::White, cell(&buffer, 3, 0).bg);
+        assert_eq!(Color::White, cell(&buffer, 9, 4).bg);
+    }
+
+    #[test]
+    fn should_clip_to_area_within_block() {
+        let area = Rect::new(0, 0, 6, 4);
+        let mut buffer = Buffer::empty(area);
+
+        create_widget()
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL))
+            .render(area, &mut buffer);
+
+        // Inner area of 4 * 2 cells shows the top of O and the space before K
+        let inner: Vec<Vec<Color>> = (1..3)
+            .map(|y| (1..5).map(|x| cell(&buffer, x, y).bg).collect())
+            .collect();
+        assert_eq!(
+            vec![
+                vec![Color::White, Color::White, Color::White, Color::Black],
+                vec![Color::White, Color::Black, Color::White, Color::Black],
+            ],
+            inner
+        );
+        // Columns of K and rows below are cut off by the border
+        assert_eq!(
+            vec!["┌────┐", "│    │", "│    │", "└────┘"],
+            render_symbols(&buffer)
+        );
+        assert!((0..4).all(|y| cell(&buffer, 5, y).bg == Color::Reset));
+        assert!((0..6).all(|x| cell(&buffer, x, 3).bg == Color::Reset));
+    }
+}
@@ -0,0 +1,1397 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use super::{BigText, BlockColor};
+use crate::font::Font;
+use crate::grid_block::{BlockGridBuilder, BlockSize, GridBlock};
+use crate::seven_segment::Segments;
+use crate::styled_cell::StyledCell;
+use crate::time_format::{self, ClockStyle, DurationFormat};
+use std::time::Duration;
+
+type CreateBlock<C> = fn(C, C) -> BlockGridBuilder<<C as BlockColor>::Span>;
+
+/// Returns the function creating the built-in block for `symbol`.
+/// Lower case letters have the same blocks as upper case ones.
+fn char_block<C: BlockColor>(symbol: char) -> Option<CreateBlock<C>> {
+    let create: CreateBlock<C> = match symbol {
+        '0' => super::create_tui_block_0,
+        '1' => super::create_tui_block_1,
+        '2' => super::create_tui_block_2,
+        '3' => super::create_tui_block_3,
+        '4' => super::create_tui_block_4,
+        '5' => super::create_tui_block_5,
+        '6' => super::create_tui_block_6,
+        '7' => super::create_tui_block_7,
+        '8' => super::create_tui_block_8,
+        '9' => super::create_tui_block_9,
+        ':' => super::create_tui_block_double_point,
+        '-' => super::create_tui_block_minus,
+        '+' => super::create_tui_block_plus,
+        '.' => super::create_tui_block_decimal_point,
+        ' ' => create_space,
+        'A' | 'a' => super::create_tui_block_a,
+        'B' | 'b' => super::create_tui_block_b,
+        'C' | 'c' => super::create_tui_block_c,
+        'D' | 'd' => super::create_tui_block_d,
+        'E' | 'e' => super::create_tui_block_e,
+        'F' | 'f' => super::create_tui_block_f,
+        'G' | 'g' => super::create_tui_block_g,
+        'H' | 'h' => super::create_tui_block_h,
+        'I' | 'i' => super::create_tui_block_i,
+        'J' | 'j' => super::create_tui_block_j,
+        'K' | 'k' => super::create_tui_block_k,
+        'L' | 'l' => super::create_tui_block_l,
+        'M' | 'm' => super::create_tui_block_m,
+        'N' | 'n' => super::create_tui_block_n,
+        'O' | 'o' => super::create_tui_block_o,
+        'P' | 'p' => super::create_tui_block_p,
+        'Q' | 'q' => super::create_tui_block_q,
+        'R' | 'r' => super::create_tui_block_r,
+        'S' | 's' => super::create_tui_block_s,
+        'T' | 't' => super::create_tui_block_t,
+        'U' | 'u' => super::create_tui_block_u,
+        'V' | 'v' => super::create_tui_block_v,
+        'W' | 'w' => super::create_tui_block_w,
+        'X' | 'x' => super::create_tui_block_x,
+        'Y' | 'y' => super::create_tui_block_y,
+        'Z' | 'z' => super::create_tui_block_z,
+        _ => return None,
+    };
+
+    Some(create)
+}
+
+fn create_space<C: BlockColor>(default_bg: C, _bg: C) -> BlockGridBuilder<C::Span> {
+    super::create_tui_block_space(default_bg)
+}
+
+/// Reasons why blocks could not be appended to a [`LineBlockBuilder`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineBlockError {
+    /// There is no block for the contained character.
+    UnknownGlyph(char),
+    /// Hours are greater than 23 or minutes or seconds greater than 59.
+    InvalidTime { hours: u8, minutes: u8, seconds: u8 },
+}
+
+impl Display for LineBlockError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownGlyph(unknown) => write!(f, "No block exists for character {unknown:?}"),
+            Self::InvalidTime {
+                hours,
+                minutes,
+                seconds,
+            } => write!(f, "{hours}:{minutes}:{seconds} is no valid time of day"),
+        }
+    }
+}
+
+impl Error for LineBlockError {}
+
+pub struct LineBlockBuilder<C: BlockColor> {
+    block_size: BlockSize,
+    taken_value: C,
+    default_value: C,
+    builders: Vec<BlockGridBuilder<C::Span>>,
+    plain_text: String,
+    font: Option<Font>,
+    ghost_value: Option<C>,
+    clock_style: ClockStyle,
+}
+
+/// How [`LineBlockBuilder::number_padded`] fills digits missing for the minimal number of digits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pad {
+    /// Missing digits are leading zeros.
+    Zero,
+    /// Missing digits are empty blocks as wide as a digit.
+    Blank,
+}
+
+/// How [`LineBlockBuilder::number_grouped`] separates groups of digits.
+/// Separators are small glyphs only one block wide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Grouping {
+    /// Groups of 3 digits separated by a comma like 1,234,567
+    Comma,
+    /// Groups of 3 digits separated by a point like 1.234.567
+    Dot,
+    /// Groups of 3 digits separated by a thin space like 1 234 567
+    ThinSpace,
+    /// Indian numbering with lakh and crore. The last 3 digits form a group and all other
+    /// groups have 2 digits like 12,34,567.
+    Indian,
+}
+
+impl Grouping {
+    fn separator(self) -> char {
+        match self {
+            Self::Comma | Self::Indian => ',',
+            Self::Dot => '.',
+            Self::ThinSpace => THIN_SPACE,
+        }
+    }
+
+    /// Returns the digits of `number` with separators between the groups.
+    fn apply(self, number: u64) -> String {
+        let digits = number.to_string();
+        let group_size = if self == Self::Indian { 2 } else { 3 };
+        let (leading, last_group) = digits.split_at(digits.len().saturating_sub(3));
+
+        let mut groups: Vec<&str> = leading
+            .as_bytes()
+            .rchunks(group_size)
+            .rev()
+            .map(|group| std::str::from_utf8(group).expect("Unexpected: digits are ascii"))
+            .collect();
+        groups.push(last_group);
+
+        groups.join(&self.separator().to_string())
+    }
+}
+
+const THIN_SPACE: char = '\u{2009}';
+
+/// Base of the digits appended by [`LineBlockBuilder::number_radix`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base {
+    /// Digits 0 and 1 with prefix 0b
+    Binary,
+    /// Digits 0 to 7 with prefix 0o
+    Octal,
+    /// Digits 0 to 9 and A to F with prefix 0x
+    Hexadecimal,
+}
+
+impl Base {
+    fn prefix(self) -> &'static str {
+        match self {
+            Self::Binary => "0b",
+            Self::Octal => "0o",
+            Self::Hexadecimal => "0x",
+        }
+    }
+
+    fn bits_per_digit(self) -> usize {
+        match self {
+            Self::Binary => 1,
+            Self::Octal => 3,
+            Self::Hexadecimal => 4,
+        }
+    }
+}
+
+/// Groups of bits separated by a thin space in [`LineBlockBuilder::number_radix`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BitGrouping {
+    #[default]
+    None,
+    /// Groups of 4 bits like 1010 0001
+    Nibbles,
+    /// Groups of 8 bits like 0A 1F
+    Bytes,
+}
+
+/// How [`LineBlockBuilder::number_radix`] shows a number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Radix {
+    pub base: Base,
+    /// Number starts with the prefix of its base like 0x.
+    pub prefix: bool,
+    /// Octal digits are never grouped because they do not align with nibbles or bytes.
+    pub grouping: BitGrouping,
+}
+
+impl Radix {
+    pub fn new(base: Base, prefix: bool, grouping: BitGrouping) -> Self {
+        Self {
+            base,
+            prefix,
+            grouping,
+        }
+    }
+
+    /// Returns `number` as digits of the base with prefix and separators.
+    /// Grouped digits are padded with leading zeros to complete groups.
+    fn apply(self, number: u64) -> String {
+        let digits = match self.base {
+            Base::Binary => format!("{number:b}"),
+            Base::Octal => format!("{number:o}"),
+            Base::Hexadecimal => format!("{number:X}"),
+        };
+        let group_bits = match self.grouping {
+            BitGrouping::None => None,
+            BitGrouping::Nibbles => Some(4),
+            BitGrouping::Bytes => Some(8),
+        };
+        let group_size = group_bits
+            .filter(|bits| bits % self.base.bits_per_digit() == 0)
+            .map(|bits| bits / self.base.bits_per_digit());
+
+        let mut text = String::new();
+        if self.prefix {
+            text.push_str(self.base.prefix());
+        }
+        match group_size {
+            Some(group_size) => {
+                let padded_len = digits.len().div_ceil(group_size) * group_size;
+                let padded = format!("{digits:0>padded_len$}");
+                let groups: Vec<&str> = padded
+                    .as_bytes()
+                    .chunks(group_size)
+                    .map(|group| std::str::from_utf8(group).expect("Unexpected: digits are ascii"))
+                    .collect();
+                text.push_str(&groups.join(&THIN_SPACE.to_string()));
+            }
+            None => text.push_str(&digits),
+        }
+
+        text
+    }
+}
+
+/// How a line of blocks is rendered to fit into an available area.
+/// See [`LineBlockBuilder::fit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FitMode {
+    /// Blocks are rendered with the contained block size.
+    Blocks(BlockSize),
+    /// Blocks are rendered with the block size of the builder in half block mode.
+    HalfBlocks,
+    /// Blocks are rendered as normal text.
+    PlainText,
+}
+
+macro_rules! next_block {
+    ($name:ident, $func:ident, $symbol:literal) => {
+        pub fn $name(&mut self) -> &mut Self {
+            self.builders
+                .push(super::$func(self.default_value, self.taken_value));
+            self.plain_text.push($symbol);
+
+            self
+        }
+    };
+}
+impl<C: BlockColor> LineBlockBuilder<C> {
+    /// A block will be `block_size` elements large. A `usize` is a square block and a tuple
+    /// (width, height) allows different scaling in x and y direction.
+    pub fn new(block_size: impl Into<BlockSize>, taken_value: C, default_value: C) -> Self {
+        Self {
+            taken_value,
+            default_value,
+            block_size: block_size.into(),
+            builders: Default::default(),
+            plain_text: Default::default(),
+            font: None,
+            ghost_value: None,
+            clock_style: ClockStyle::default(),
+        }
+    }
+
+    /// Glyphs of `font` are used instead of the built-in blocks by [`LineBlockBuilder::text`]
+    /// and all methods based on it like [`LineBlockBuilder::number`].
+    /// The spacing of `font` with its kerning is put between its glyphs.
+    /// A proportional font like `Font::thin().proportional()` gives a tighter line.
+    pub fn font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+
+        self
+    }
+
+    /// Colour of unlit segments appended via [`LineBlockBuilder::segments`] like the faint
+    /// segments of a real display. Unlit segments have the default colour if not set.
+    pub fn ghost(&mut self, ghost_value: C) -> &mut Self {
+        self.ghost_value = Some(ghost_value);
+
+        self
+    }
+
+    /// Appends a seven segment glyph where only `segments` are lit.
+    /// Unlit segments have the colour given to [`LineBlockBuilder::ghost`].
+    pub fn segments(&mut self, segments: Segments) -> &mut Self {
+        let ghost_value = self.ghost_value.unwrap_or(self.default_value);
+        self.builders.push(super::create_tui_block_segments(
+            segments,
+            self.default_value,
+            ghost_value,
+            self.taken_value,
+        ));
+        match segments.to_char() {
+            // a lone decimal point is only a point in plain text
+            Some(' ') if segments.contains(Segments::DP) => (),
+            symbol => self.plain_text.push(symbol.unwrap_or('?')),
+        }
+        if segments.contains(Segments::DP) {
+            self.plain_text.push('.');
+        }
+
+        self
+    }
+
+    /// Appends a seven segment glyph for every character of `text` without space between them.
+    /// A `.` lights the decimal point of the glyph before it.
+    /// See [`Segments::from_char`] for the supported characters.
+    ///
+    /// # Errors
+    ///
+    /// If a character has no seven segment glyph. Nothing is appended in this case.
+    pub fn seven_segment(&mut self, text: &str) -> Result<&mut Self, LineBlockError> {
+        let mut glyphs: Vec<Segments> = Vec::new();
+        for next_char in text.chars() {
+            match (next_char, glyphs.last_mut()) {
+                ('.', Some(last)) if !last.contains(Segments::DP) => *last |= Segments::DP,
+                ('.', _) => glyphs.push(Segments::DP),
+                _ => glyphs.push(
+                    Segments::from_char(next_char)
+                        .ok_or(LineBlockError::UnknownGlyph(next_char))?,
+                ),
+            }
+        }
+
+        for segments in glyphs {
+            self.segments(segments);
+        }
+
+        Ok(self)
+    }
+
+    pub fn space(&mut self) -> &mut Self {
+        self.builders
+            .push(super::create_tui_block_space(self.default_value));
+        self.plain_text.push(' ');
+
+        self
+    }
+
+    next_block! {one, create_tui_block_1, '1'}
+    next_block! {two, create_tui_block_2, '2'}
+    next_block! {three, create_tui_block_3, '3'}
+    next_block! {four, create_tui_block_4, '4'}
+    next_block! {five, create_tui_block_5, '5'}
+    next_block! {six, create_tui_block_6, '6'}
+    next_block! {seven, create_tui_block_7, '7'}
+    next_block! {eight, create_tui_block_8, '8'}
+    next_block! {nine, create_tui_block_9, '9'}
+    next_block! {zero, create_tui_block_0, '0'}
+    next_block! {seperator, create_tui_block_double_point, ':'}
+    next_block! {minus, create_tui_block_minus, '-'}
+    next_block! {plus, create_tui_block_plus, '+'}
+    next_block! {decimal_point, create_tui_block_decimal_point, '.'}
+    next_block! {letter_a, create_tui_block_a, 'A'}
+    next_block! {letter_b, create_tui_block_b, 'B'}
+    next_block! {letter_c, create_tui_block_c, 'C'}
+    next_block! {letter_d, create_tui_block_d, 'D'}
+    next_block! {letter_e, create_tui_block_e, 'E'}
+    next_block! {letter_f, create_tui_block_f, 'F'}
+    next_block! {letter_g, create_tui_block_g, 'G'}
+    next_block! {letter_h, create_tui_block_h, 'H'}
+    next_block! {letter_i, create_tui_block_i, 'I'}
+    next_block! {letter_j, create_tui_block_j, 'J'}
+    next_block! {letter_k, create_tui_block_k, 'K'}
+    next_block! {letter_l, create_tui_block_l, 'L'}
+    next_block! {letter_m, create_tui_block_m, 'M'}
+    next_block! {letter_n, create_tui_block_n, 'N'}
+    next_block! {letter_o, create_tui_block_o, 'O'}
+    next_block! {letter_p, create_tui_block_p, 'P'}
+    next_block! {letter_q, create_tui_block_q, 'Q'}
+    next_block! {letter_r, create_tui_block_r, 'R'}
+    next_block! {letter_s, create_tui_block_s, 'S'}
+    next_block! {letter_t, create_tui_block_t, 'T'}
+    next_block! {letter_u, create_tui_block_u, 'U'}
+    next_block! {letter_v, create_tui_block_v, 'V'}
+    next_block! {letter_w, create_tui_block_w, 'W'}
+    next_block! {letter_x, create_tui_block_x, 'X'}
+    next_block! {letter_y, create_tui_block_y, 'Y'}
+    next_block! {letter_z, create_tui_block_z, 'Z'}
+
+    pub fn build_line(&mut self) -> Vec<C::Line> {
+        let were_build = self.build_blocks();
+
+        super::build_line_block::<C>(&were_build)
+    }
+
+    /// Like [`LineBlockBuilder::build_line`] but 2 vertically stacked elements are rendered in
+    /// one terminal cell. See [`super::build_tui_half_block_line`] for details.
+    pub fn build_half_block_line(&mut self) -> Vec<C::Line> {
+        let were_build = self.build_blocks();
+
+        super::build_tui_half_block_line(&were_build, self.default_value)
+    }
+
+    /// Returns the built blocks as styled cells independent of the tui library, for example to
+    /// export them via [`crate::ansi`].
+    pub fn build_styled_line(&mut self) -> Vec<GridBlock<StyledCell>> {
+        self.build_blocks()
+            .iter()
+            .map(|block| block.map(super::span_to_styled_cell::<C>))
+            .collect()
+    }
+
+    /// Returns the built blocks as a widget which renders directly into the buffer of a frame.
+    pub fn build_widget<'a>(&mut self) -> BigText<'a, C> {
+        BigText::new(self.build_blocks())
+    }
+
+    /// Number of terminal cells the line is wide if built via [`LineBlockBuilder::build_line`].
+    /// Can be used to plan a layout before building.
+    pub fn width(&self) -> usize {
+        let blocks_in_x: usize = self
+            .builders
+            .iter()
+            .map(|builder| builder.width_in_blocks())
+            .sum();
+
+        blocks_in_x * self.block_size.width
+    }
+
+    /// Number of terminal cells the line is high if built via [`LineBlockBuilder::build_line`].
+    pub fn height(&self) -> usize {
+        let blocks_in_y = self
+            .builders
+            .iter()
+            .map(|builder| builder.height_in_blocks())
+            .max()
+            .unwrap_or(0);
+
+        blocks_in_y * self.block_size.height
+    }
+
+    /// Returns how the line can be rendered within `width` * `height` terminal cells.
+    /// The block size given to [`LineBlockBuilder::new`] is the smallest size. It is scaled
+    /// by the largest factor at which the whole line still fits. Width and height are scaled
+    /// by the same factor.
+    ///
+    /// If the line does not fit with the smallest size, half block mode is tried.
+    /// If this does not fit either, plain text is returned as last resort.
+    pub fn fit(&self, width: u16, height: u16) -> FitMode {
+        let (needed_width, needed_height) = (self.width(), self.height());
+        let (width, height) = (usize::from(width), usize::from(height));
+
+        if needed_width == 0 || needed_height == 0 {
+            return FitMode::Blocks(self.block_size);
+        }
+
+        let factor = (width / needed_width).min(height / needed_height);
+        if factor > 0 {
+            FitMode::Blocks(BlockSize::new(
+                self.block_size.width * factor,
+                self.block_size.height * factor,
+            ))
+        } else if needed_width <= width && needed_height.div_ceil(2) <= height {
+            FitMode::HalfBlocks
+        } else {
+            FitMode::PlainText
+        }
+    }
+
+    /// Builds the line in the largest form which fits into `area`. See [`LineBlockBuilder::fit`]
+    /// for details.
+    pub fn build_fitted(&mut self, area: C::Rect) -> Vec<C::Line> {
+        let (_, _, width, height) = C::bounds_of(area);
+        match self.fit(width, height) {
+            FitMode::Blocks(block_size) => {
+                let were_build = self.build_blocks_with(block_size);
+                super::build_line_block::<C>(&were_build)
+            }
+            FitMode::HalfBlocks => self.build_half_block_line(),
+            FitMode::PlainText => vec![C::line(vec![C::span(
+                self.plain_text.clone(),
+                Some(self.taken_value),
+                None,
+            )])],
+        }
+    }
+
+    fn build_blocks(&mut self) -> Vec<GridBlock<C::Span>> {
+        self.build_blocks_with(self.block_size)
+    }
+
+    fn build_blocks_with(&mut self, block_size: BlockSize) -> Vec<GridBlock<C::Span>> {
+        self.builders
+            .iter_mut()
+            .map(|builder| builder.block_dimensions(block_size).build())
+            .collect()
+    }
+
+    /// Appends a block for every digit of `number` with a space between them.
+    ///
+    /// # Panic
+    ///
+    /// If a font map is used which has no glyph for a digit.
+    pub fn number(&mut self, number: u32) -> &mut Self {
+        self.text(&number.to_string())
+            .expect("Unexpected: a number as digit could not mapped.")
+    }
+
+    /// Appends `number` with at least `min_digits` digits. Missing digits are filled on the left
+    /// according to `pad`. This keeps the width of the line stable while the number changes.
+    /// Every digit is centred within the width of the widest digit of the font and no kerning
+    /// is applied, so the width is stable for proportional fonts too.
+    ///
+    /// # Panic
+    ///
+    /// If a font is used which has no glyph for a digit.
+    pub fn number_padded(&mut self, number: u32, min_digits: usize, pad: Pad) -> &mut Self {
+        let digits = number.to_string();
+        let missing = min_digits.saturating_sub(digits.len());
+        let padding = match pad {
+            Pad::Zero => (0..missing)
+                .map(|_| ('0', self.digit_glyph('0')))
+                .collect::<Vec<_>>(),
+            Pad::Blank => (0..missing).map(|_| (' ', self.blank_digit())).collect(),
+        };
+        let glyphs = padding
+            .into_iter()
+            .chain(digits.chars().map(|digit| (digit, self.digit_glyph(digit))))
+            .map(|(symbol, glyph)| glyph.map(|glyph| (symbol, glyph)))
+            .collect::<Option<Vec<_>>>()
+            .expect("Unexpected: a number as digit could not mapped.");
+
+        self.push_glyphs(glyphs, false);
+
+        self
+    }
+
+    /// Appends `number` with its digits in groups. See [`Grouping`] for the separators.
+    /// Separators are as high as the digits of the used font.
+    ///
+    /// # Errors
+    ///
+    /// If a font is used which has no glyph for a digit. Nothing is appended in this case.
+    pub fn number_grouped(
+        &mut self,
+        number: u64,
+        grouping: Grouping,
+    ) -> Result<&mut Self, LineBlockError> {
+        self.grouped_digits(&grouping.apply(number))
+    }
+
+    /// Appends `number` with the digits of another base. See [`Radix`] for prefix and grouping.
+    /// Hexadecimal digits greater than 9 are the letters A to F.
+    ///
+    /// # Errors
+    ///
+    /// If a font is used which has no glyph for a digit, a letter or the prefix of the number.
+    /// Nothing is appended in this case.
+    pub fn number_radix(&mut self, number: u64, radix: Radix) -> Result<&mut Self, LineBlockError> {
+        self.grouped_digits(&radix.apply(number))
+    }
+
+    /// Appends digits and letters of `text` with small glyphs for group separators.
+    /// Separators get the spacing of the font on both sides like any other glyph, so a thin
+    /// space is wider than the gap between digits.
+    /// Lower case letters fall back to upper case glyphs for fonts with only upper case letters.
+    fn grouped_digits(&mut self, text: &str) -> Result<&mut Self, LineBlockError> {
+        let height = self.glyph('0').map_or(5, |digit| digit.height_in_blocks());
+        let glyphs = text
+            .chars()
+            .map(|symbol| {
+                let glyph = match symbol {
+                    ',' => Some(super::create_tui_block_small_comma(
+                        self.default_value,
+                        self.taken_value,
+                        height,
+                    )),
+                    '.' => Some(super::create_tui_block_small_point(
+                        self.default_value,
+                        self.taken_value,
+                        height,
+                    )),
+                    THIN_SPACE => Some(super::create_tui_block_space_of_size(
+                        self.default_value,
+                        1,
+                        height,
+                    )),
+                    symbol => self
+                        .glyph(symbol)
+                        .or_else(|| self.glyph(symbol.to_ascii_uppercase())),
+                };
+                glyph
+                    .map(|glyph| (symbol, glyph))
+                    .ok_or(LineBlockError::UnknownGlyph(symbol))
+            })
+            .collect::<Result<Vec<_>, LineBlockError>>()?;
+
+        self.push_glyphs(glyphs, true);
+
+        Ok(self)
+    }
+
+    /// Like [`LineBlockBuilder::number`] but prefixed with a minus for negative numbers.
+    pub fn signed(&mut self, number: i64) -> &mut Self {
+        self.text(&number.to_string())
+            .expect("Unexpected: a signed number has a character without block.")
+    }
+
+    /// Appends `number` rounded to `precision` digits after the decimal point.
+    /// Rounds like [`format!`] which rounds ties to even, so 12.5 with precision 0 is 12.
+    /// A number which is rounded to zero is shown without minus.
+    /// NaN is shown as "NAN" and infinity as "INF" with its sign.
+    /// `precision` is limited to [`u16::MAX`] digits, the most [`format!`] supports.
+    ///
+    /// # Errors
+    ///
+    /// If a font is used which has no glyph for a character of the number like the letters of
+    /// NAN and INF. Nothing is appended in this case.
+    pub fn float(&mut self, number: f64, precision: usize) -> Result<&mut Self, LineBlockError> {
+        let as_text = if number.is_nan() {
+            "NAN".to_string()
+        } else if number.is_infinite() {
+            let sign = if number.is_sign_negative() { "-" } else { "" };
+            format!("{sign}INF")
+        } else {
+            let precision = precision.min(usize::from(u16::MAX));
+            let rounded = format!("{number:.precision$}");
+            match rounded.strip_prefix('-') {
+                Some(unsigned) if unsigned.chars().all(|next| matches!(next, '0' | '.')) => {
+                    unsigned.to_string()
+                }
+                _ => rounded,
+            }
+        };
+
+        self.text(&as_text)
+    }
+
+    /// Appends `duration` with zero padded fields like `01:05:09`.
+    /// See [`time_format::format_duration`] for details.
+    ///
+    /// # Panic
+    ///
+    /// If a font is used which has no glyph for a digit, `:` or `.`.
+    pub fn duration(&mut self, duration: Duration, format: DurationFormat) -> &mut Self {
+        self.text(&time_format::format_duration(duration, format))
+            .expect("Unexpected: a duration has a character without block.")
+    }
+
+    /// How [`LineBlockBuilder::time_of_day`] shows the hour. Is 24 hour style by default.
+    pub fn clock_style(&mut self, clock_style: ClockStyle) -> &mut Self {
+        self.clock_style = clock_style;
+
+        self
+    }
+
+    /// Appends the time of day like `13:05:09` or `01:05:09 PM` in 12 hour style.
+    /// See [`LineBlockBuilder::clock_style`].
+    ///
+    /// # Errors
+    ///
+    /// - If the time is invalid.
+    /// - If a font is used which has no glyph for a character of the time.
+    ///
+    /// Nothing is appended in both cases.
+    pub fn time_of_day(
+        &mut self,
+        hours: u8,
+        minutes: u8,
+        seconds: u8,
+    ) -> Result<&mut Self, LineBlockError> {
+        let as_text = time_format::format_time_of_day(hours, minutes, seconds, self.clock_style)
+            .ok_or(LineBlockError::InvalidTime {
+                hours,
+                minutes,
+                seconds,
+            })?;
+
+        self.text(&as_text)
+    }
+
+    /// Appends a block for every character in `text`. Like [`LineBlockBuilder::number`] a space
+    /// is put between every block.
+    ///
+    /// # Errors
+    ///
+    /// If there is no block for a character in `text`. Nothing is appended in this case.
+    pub fn text(&mut self, text: &str) -> Result<&mut Self, LineBlockError> {
+        let glyphs = text
+            .chars()
+            .map(|next_char| {
+                self.glyph(next_char)
+                    .ok_or(LineBlockError::UnknownGlyph(next_char))
+            })
+            .collect::<Result<Vec<BlockGridBuilder<C::Span>>, LineBlockError>>()?;
+
+        self.push_glyphs(text.chars().zip(glyphs).collect(), true);
+
+        Ok(self)
+    }
+
+    /// Appends every glyph with the spacing of the font between them.
+    /// The character of a glyph is used as plain text and for kerning if `kerning` is true.
+    fn push_glyphs(&mut self, glyphs: Vec<(char, BlockGridBuilder<C::Span>)>, kerning: bool) {
+        let mut previous: Option<char> = None;
+        for (next_char, next) in glyphs {
+            let spacing = match (previous, &self.font) {
+                (None, _) => 0,
+                (Some(left), Some(font)) if kerning => font.spacing_between(left, next_char),
+                (Some(_), Some(font)) => font.spacing(),
+                (Some(_), None) => 1,
+            };
+            previous = Some(next_char);
+            if spacing > 0 {
+                self.builders.push(super::create_tui_block_space_of_size(
+                    self.default_value,
+                    spacing,
+                    next.height_in_blocks(),
+                ));
+            }
+
+            self.builders.push(next);
+            self.plain_text.push(next_char);
+        }
+    }
+
+    /// Returns a glyph as large as a digit with only not set elements.
+    fn blank_digit(&self) -> Option<BlockGridBuilder<C::Span>> {
+        let default_value = super::element(self.default_value);
+        self.digit_glyph('0')
+            .map(|glyph| glyph.map(|_| default_value.clone()))
+    }
+
+    /// Returns the glyph of `digit` as wide as the widest digit of the font.
+    fn digit_glyph(&self, digit: char) -> Option<BlockGridBuilder<C::Span>> {
+        match &self.font {
+            Some(font) => {
+                let width = font.digit_advance().unwrap_or(0);
+                font.glyph_with_advance(digit, width).map(|glyph| {
+                    super::create_tui_block_from_glyph(&glyph, self.default_value, self.taken_value)
+                })
+            }
+            None => self.glyph(digit),
+        }
+    }
+
+    fn glyph(&self, symbol: char) -> Option<BlockGridBuilder<C::Span>> {
+        match &self.font {
+            Some(font) => font.glyph(symbol).map(|glyph| {
+                super::create_tui_block_from_glyph(glyph, self.default_value, self.taken_value)
+            }),
+            None => char_block(symbol).map(|create| create(self.default_value, self.taken_value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::super::test_backend::{spans_of, Color, Rect, Span, Spans, Style};
+    use super::*;
+    use crate::font::{self, FontRegistry};
+    use crate::styled_cell::CellColor;
+    use crate::time_format::Hours;
+
+    fn create_builder() -> LineBlockBuilder<Color> {
+        LineBlockBuilder::new(1, Color::White, Color::Black)
+    }
+
+    #[test]
+    fn should_build_text_like_number() {
+        let from_text = create_builder()
+            .text("1207")
+            .expect("Digits should be known")
+            .build_line();
+        let from_number = create_builder().number(1207).build_line();
+
+        assert_eq!(from_number, from_text);
+    }
+
+    #[test]
+    fn should_build_text_with_letters_and_seperator() {
+        let actual = create_builder()
+            .text("12:05 AM")
+            .expect("All characters should be known")
+            .build_line();
+        let expected = create_builder()
+            .one()
+            .space()
+            .two()
+            .space()
+            .seperator()
+            .space()
+            .zero()
+            .space()
+            .five()
+            .space()
+            .space()
+            .space()
+            .letter_a()
+            .space()
+            .letter_m()
+            .build_line();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn should_build_signed() {
+        let actual = create_builder().signed(-42).build_line();
+        let expected = create_builder()
+            .minus()
+            .space()
+            .four()
+            .space()
+            .two()
+            .build_line();
+
+        assert_eq!(expected, actual);
+        assert_eq!(
+            create_builder().number(42).build_line(),
+            create_builder().signed(42).build_line()
+        );
+    }
+
+    #[test]
+    fn should_round_float() {
+        assert_float_as_text(0.96, 1, "1.0");
+        assert_float_as_text(-2.71868, 3, "-2.719");
+        assert_float_as_text(12.5, 0, "12");
+        assert_float_as_text(13.5, 0, "14");
+        assert_float_as_text(-7.0, 2, "-7.00");
+    }
+
+    #[test]
+    fn should_build_float_without_minus_for_zero() {
+        assert_float_as_text(-0.0, 2, "0.00");
+        assert_float_as_text(-0.001, 1, "0.0");
+        assert_float_as_text(-0.0, 0, "0");
+    }
+
+    #[test]
+    fn should_build_float_not_finite() {
+        assert_float_as_text(f64::NAN, 2, "NAN");
+        assert_float_as_text(f64::INFINITY, 2, "INF");
+        assert_float_as_text(f64::NEG_INFINITY, 2, "-INF");
+    }
+
+    #[test]
+    fn should_limit_precision_of_float() {
+        let mut builder = create_builder();
+        builder
+            .float(1.5, usize::MAX)
+            .expect("Digits and point should be known");
+
+        assert_eq!(2 + usize::from(u16::MAX), builder.plain_text.len());
+        assert!(builder.plain_text.starts_with("1.50"));
+    }
+
+    #[test]
+    fn should_not_append_float_without_glyphs() {
+        let mut builder = create_builder();
+        builder.font(Font::seven_segment());
+
+        let actual = builder.float(f64::NAN, 1).err();
+
+        assert_eq!(Some(LineBlockError::UnknownGlyph('N')), actual);
+        assert!(builder.builders.is_empty());
+    }
+
+    fn assert_float_as_text(number: f64, precision: usize, expected: &str) {
+        let actual = create_builder()
+            .float(number, precision)
+            .expect("Characters of float should be known")
+            .build_line();
+        let expected = create_builder()
+            .text(expected)
+            .expect("Expected text should be known")
+            .build_line();
+
+        assert_eq!(
+            expected, actual,
+            "number {number} with precision {precision}"
+        );
+    }
+
+    #[test]
+    fn should_half_the_rows_in_half_block_mode() {
+        let mut builder = LineBlockBuilder::new(2, Color::White, Color::Black);
+        builder.number(42);
+
+        let full = builder.build_line();
+        let half = builder.build_half_block_line();
+
+        assert_eq!(10, full.len());
+        assert_eq!(5, half.len());
+        assert_eq!(full[0].width(), half[0].width());
+    }
+
+    #[test]
+    fn should_scale_width_and_height_independently() {
+        let actual = LineBlockBuilder::new((2, 1), Color::White, Color::Black)
+            .number(42)
+            .build_line();
+
+        assert_eq!(5, actual.len());
+        assert_eq!(14, actual[0].width());
+    }
+
+    #[test]
+    fn should_fit_largest_block_size() {
+        let mut builder = create_builder();
+        builder.number(42);
+
+        assert_eq!(FitMode::Blocks(BlockSize::new(1, 1)), builder.fit(7, 5));
+        assert_eq!(FitMode::Blocks(BlockSize::new(2, 2)), builder.fit(20, 10));
+        assert_eq!(FitMode::Blocks(BlockSize::new(3, 3)), builder.fit(21, 100));
+        assert_eq!(FitMode::Blocks(BlockSize::new(2, 2)), builder.fit(100, 14));
+    }
+
+    #[test]
+    fn should_keep_ratio_of_block_size_during_fit() {
+        let mut builder = LineBlockBuilder::new((2, 1), Color::White, Color::Black);
+        builder.number(42);
+
+        assert_eq!(FitMode::Blocks(BlockSize::new(4, 2)), builder.fit(30, 10));
+    }
+
+    #[test]
+    fn should_fall_back_to_half_block_and_plain_text() {
+        let mut builder = create_builder();
+        builder.text("OK").expect("Letters should be known");
+
+        assert_eq!(FitMode::HalfBlocks, builder.fit(7, 3));
+        assert_eq!(FitMode::PlainText, builder.fit(6, 5));
+        assert_eq!(FitMode::PlainText, builder.fit(7, 2));
+
+        let actual = builder.build_fitted(Rect::new(0, 0, 6, 5));
+        assert_eq!(
+            vec![Spans::from(Span::styled(
+                "OK",
+                Style::default().fg(Color::White)
+            ))],
+            actual
+        );
+    }
+
+    #[test]
+    fn should_build_fitted_blocks() {
+        let mut builder = create_builder();
+        builder.number(7);
+
+        let actual = builder.build_fitted(Rect::new(0, 0, 10, 10));
+
+        assert_eq!(10, actual.len());
+        assert_eq!(6, actual[0].width());
+    }
+
+    /// Set elements are # and all other .
+    fn to_symbols(lines: &[Spans<'static>]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| {
+                spans_of(line)
+                    .iter()
+                    .map(|span| match span.style.bg {
+                        Some(Color::White) => '#',
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn create_font(source: &str, spacing: usize) -> Font {
+        let glyphs = crate::font::parse_text_font(source).expect("Font should be valid");
+        Font::new(glyphs, 3, spacing, 3).expect("Glyphs should be as high as the font")
+    }
+
+    const FONT_1_7: &str = "width = 2
+        height = 3
+        [1]
+        .#
+        ##
+        .#
+        [7]
+        ##
+        .#
+        .#";
+
+    #[test]
+    fn should_use_glyphs_of_font() {
+        let actual = create_builder()
+            .font(create_font(FONT_1_7, 1))
+            .number(17)
+            .build_line();
+
+        assert_eq!(vec![".#.##", "##..#", ".#..#"], to_symbols(&actual));
+    }
+
+    #[test]
+    fn should_use_spacing_of_font() {
+        let without_spacing = create_builder()
+            .font(create_font(FONT_1_7, 0))
+            .number(717)
+            .build_line();
+        let wide_spacing = create_builder()
+            .font(create_font(FONT_1_7, 2))
+            .number(71)
+            .build_line();
+
+        assert_eq!(
+            vec!["##.###", ".###.#", ".#.#.#"],
+            to_symbols(&without_spacing)
+        );
+        assert_eq!(
+            vec!["##...#", ".#..##", ".#...#"],
+            to_symbols(&wide_spacing)
+        );
+    }
+
+    #[test]
+    fn should_honour_kerning_and_proportional_widths() {
+        let mut font = Font::thin().proportional();
+        font.set_kerning('1', ':', -1);
+
+        let actual = create_builder()
+            .font(font)
+            .text("11:8")
+            .expect("Thin font should have digits")
+            .build_line();
+
+        assert_eq!(
+            vec!["#.#..###", "#.##.#.#", "#.#..###", "#.##.#.#", "#.#..###"],
+            to_symbols(&actual)
+        );
+    }
+
+    #[test]
+    fn should_render_built_in_fonts() {
+        let bold = create_builder()
+            .font(Font::bold())
+            .text("12:05")
+            .expect("Built-in font should have digits")
+            .build_line();
+
+        assert_eq!(7, bold.len());
+        assert_eq!(5 * 5 + 4, bold[0].width());
+    }
+
+    #[test]
+    fn should_build_lines_concurrently() {
+        let handles: Vec<_> = (0..4)
+            .map(|number| std::thread::spawn(move || create_builder().number(number).build_line()))
+            .collect();
+
+        for (number, handle) in handles.into_iter().enumerate() {
+            let actual = handle.join().expect("Building should not panic");
+            let expected = create_builder().number(number as u32).build_line();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn should_group_digits() {
+        let to_grouped = |grouping: Grouping| {
+            let mut builder = create_builder();
+            builder
+                .number_grouped(1_234_567, grouping)
+                .expect("Digits should have glyphs");
+            builder.plain_text
+        };
+
+        assert_eq!("1,234,567", to_grouped(Grouping::Comma));
+        assert_eq!("1.234.567", to_grouped(Grouping::Dot));
+        assert_eq!("1\u{2009}234\u{2009}567", to_grouped(Grouping::ThinSpace));
+        assert_eq!("12,34,567", to_grouped(Grouping::Indian));
+        assert_eq!("999", Grouping::Indian.apply(999));
+        assert_eq!("1,00,000", Grouping::Indian.apply(100_000));
+        assert_eq!("100,000", Grouping::Comma.apply(100_000));
+    }
+
+    #[test]
+    fn should_show_number_in_radix() {
+        let to_text = |number: u64, radix: Radix| {
+            let mut builder = create_builder();
+            builder
+                .number_radix(number, radix)
+                .expect("Digits should have glyphs");
+            builder.plain_text.replace(THIN_SPACE, " ")
+        };
+
+        assert_eq!(
+            "0xBEEF",
+            to_text(
+                0xBEEF,
+                Radix::new(Base::Hexadecimal, true, BitGrouping::None)
+            )
+        );
+        assert_eq!(
+            "01 2C",
+            to_text(
+                300,
+                Radix::new(Base::Hexadecimal, false, BitGrouping::Bytes)
+            )
+        );
+        assert_eq!(
+            "0b0001 0110",
+            to_text(22, Radix::new(Base::Binary, true, BitGrouping::Nibbles))
+        );
+        assert_eq!(
+            "00010110",
+            to_text(22, Radix::new(Base::Binary, false, BitGrouping::Bytes))
+        );
+        assert_eq!(
+            "0o755",
+            to_text(0o755, Radix::new(Base::Octal, true, BitGrouping::Bytes))
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_upper_case_glyph_of_font() {
+        let mut builder = create_builder();
+        builder
+            .font(Font::thin())
+            .number_radix(255, Radix::new(Base::Hexadecimal, true, BitGrouping::None))
+            .expect("Lower case letters should fall back to upper case glyphs");
+
+        assert_eq!("0xFF", builder.plain_text);
+        assert_eq!(4 + 3, builder.builders.len());
+    }
+
+    #[test]
+    fn should_show_hexadecimal_number_with_every_built_in_font() {
+        let registry = FontRegistry::with_built_in();
+
+        for name in [font::THIN, font::BOLD, font::SEVEN_SEGMENT] {
+            let font = registry
+                .get(name)
+                .expect("Built-in font should be registered");
+            let mut builder = create_builder();
+            builder
+                .font(font.clone())
+                .number_radix(
+                    0xFF,
+                    Radix::new(Base::Hexadecimal, false, BitGrouping::None),
+                )
+                .unwrap_or_else(|error| panic!("{name} should show 0xFF: {error}"));
+
+            assert_eq!("FF", builder.plain_text, "{name}");
+        }
+    }
+
+    #[test]
+    fn should_not_append_number_with_unknown_glyph() {
+        let mut builder = create_builder();
+        builder.font(create_font(FONT_1_7, 1));
+
+        let actual = builder
+            .number_radix(
+                0x1F,
+                Radix::new(Base::Hexadecimal, false, BitGrouping::None),
+            )
+            .err();
+
+        assert_eq!(Some(LineBlockError::UnknownGlyph('F')), actual);
+        assert!(builder.builders.is_empty());
+        assert_eq!("", builder.plain_text);
+    }
+
+    #[test]
+    fn should_know_width_of_grouped_number_before_build() {
+        let mut builder = LineBlockBuilder::new((2, 1), Color::White, Color::Black);
+        builder
+            .number_grouped(1234, Grouping::Comma)
+            .expect("Digits should have glyphs");
+
+        // 4 digits with 3 blocks, 1 comma and 4 spaces
+        assert_eq!(2 * (4 * 3 + 1 + 4), builder.width());
+        assert_eq!(5, builder.height());
+
+        let actual = builder.build_line();
+        assert_eq!(builder.width(), actual[0].width());
+        // Comma is the 4th block after 1 and a space
+        let comma: Vec<String> = to_symbols(&actual)
+            .iter()
+            .map(|row| row[8..10].to_string())
+            .collect();
+        assert_eq!(vec!["..", "..", "..", "##", "##"], comma);
+    }
+
+    /// Returns the columns of `line` from `start` to `end` in every row.
+    fn columns_of(line: &[Spans<'static>], start: usize, end: usize) -> Vec<String> {
+        to_symbols(line)
+            .iter()
+            .map(|row| row[start..end].to_string())
+            .collect()
+    }
+
+    #[test]
+    fn should_keep_separators_apart_from_digits() {
+        let mut comma = create_builder();
+        comma
+            .number_grouped(1234, Grouping::Comma)
+            .expect("Digits should have glyphs");
+        let mut thin_space = create_builder();
+        thin_space
+            .number_grouped(1234, Grouping::ThinSpace)
+            .expect("Digits should have glyphs");
+        let plain = create_builder().number(1234).build_line();
+
+        // Last column of 1, a space, the comma, a space and the first column of 2
+        assert_eq!(
+            vec!["#...#", "#....", "#...#", "#.#.#", "#.#.#"],
+            columns_of(&comma.build_line(), 2, 7)
+        );
+        // Thin space is 1 block wider than the gap between digits
+        let thin_space = thin_space.build_line();
+        assert_eq!(plain[0].width() + 2, thin_space[0].width());
+        assert_eq!(vec!["..."; 5], columns_of(&thin_space, 3, 6));
+        assert_ne!(plain, thin_space);
+    }
+
+    #[test]
+    fn should_show_bit_groups_as_gap() {
+        let mut grouped = create_builder();
+        grouped
+            .number_radix(
+                0xABCD,
+                Radix::new(Base::Hexadecimal, false, BitGrouping::Bytes),
+            )
+            .expect("Hexadecimal digits should have glyphs");
+        let ungrouped = create_builder()
+            .text("ABCD")
+            .expect("Letters should have glyphs")
+            .build_line();
+
+        let grouped = grouped.build_line();
+        assert_eq!(ungrouped[0].width() + 2, grouped[0].width());
+        // B ends at column 7, then a gap of 3 columns before C
+        assert_eq!(vec!["..."; 5], columns_of(&grouped, 7, 10));
+        assert_ne!(ungrouped, grouped);
+    }
+
+    #[test]
+    fn should_pad_number_to_min_digits() {
+        let mut zeros = create_builder();
+        zeros.number_padded(7, 3, Pad::Zero);
+        let mut blanks = create_builder();
+        blanks.number_padded(7, 3, Pad::Blank);
+        let mut too_long = create_builder();
+        too_long.number_padded(1234, 3, Pad::Blank);
+
+        assert_eq!("007", zeros.plain_text);
+        assert_eq!("  7", blanks.plain_text);
+        assert_eq!("1234", too_long.plain_text);
+
+        let (zeros, blanks) = (zeros.build_line(), blanks.build_line());
+        assert_eq!(zeros[0].width(), blanks[0].width());
+        assert_eq!(vec!["........###", "..........#"], to_symbols(&blanks)[..2]);
+    }
+
+    #[test]
+    fn should_keep_width_of_padded_number_with_proportional_font() {
+        let mut font = Font::thin().proportional();
+        font.set_kerning('1', '1', -1);
+        let to_width = |number: u32, pad: Pad| {
+            let mut builder = create_builder();
+            builder.font(font.clone()).number_padded(number, 3, pad);
+            builder.width()
+        };
+
+        let width = to_width(888, Pad::Zero);
+        assert_eq!(3 * 3 + 2, width);
+        assert_eq!(width, to_width(111, Pad::Zero));
+        assert_eq!(width, to_width(1, Pad::Zero));
+        assert_eq!(width, to_width(1, Pad::Blank));
+        assert_eq!(width, to_width(11, Pad::Blank));
+    }
+
+    #[test]
+    fn should_append_duration_and_time_of_day() {
+        let mut builder = create_builder();
+        builder
+            .duration(
+                Duration::from_millis(65_250),
+                DurationFormat::new(Hours::IfNeeded, true),
+            )
+            .space()
+            .clock_style(ClockStyle::TwelveHour)
+            .time_of_day(23, 59, 1)
+            .expect("Time should be valid");
+
+        assert_eq!("01:05.250 11:59:01 PM", builder.plain_text);
+        assert_eq!(
+            Err(LineBlockError::InvalidTime {
+                hours: 24,
+                minutes: 0,
+                seconds: 0
+            }),
+            builder.time_of_day(24, 0, 0).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn should_show_unlit_segments_in_ghost_colour() {
+        let actual = create_builder()
+            .ghost(Color::DarkGray)
+            .seven_segment("1.")
+            .expect("Digit and point have seven segment glyphs")
+            .build_line();
+
+        let colors: Vec<Vec<Color>> = actual
+            .iter()
+            .map(|line| {
+                spans_of(line)
+                    .iter()
+                    .map(|span| span.style.bg.unwrap())
+                    .collect()
+            })
+            .collect();
+        let (ghost, lit, empty) = (Color::DarkGray, Color::White, Color::Black);
+        assert_eq!(vec![empty, ghost, ghost, empty, empty], colors[0]);
+        assert_eq!(vec![ghost, empty, empty, lit, empty], colors[1]);
+        assert_eq!(vec![empty, ghost, ghost, empty, lit], colors[6]);
+    }
+
+    #[test]
+    fn should_put_decimal_point_on_seven_segment_glyph() {
+        let mut builder = create_builder();
+        builder
+            .seven_segment(".2.5")
+            .expect("Digits and points have seven segment glyphs");
+
+        assert_eq!(3, builder.builders.len());
+        assert_eq!(".2.5", builder.plain_text);
+        assert_eq!(
+            Err(LineBlockError::UnknownGlyph('x')),
+            builder.seven_segment("1x").map(|_| ())
+        );
+        assert_eq!(3, builder.builders.len());
+    }
+
+    #[test]
+    fn should_name_unknown_char_of_font() {
+        let actual = create_builder()
+            .font(create_font(FONT_1_7, 1))
+            .text("12")
+            .map(|_| ())
+            .unwrap_err();
+
+        assert_eq!(LineBlockError::UnknownGlyph('2'), actual);
+    }
+
+    #[test]
+    fn should_name_unknown_char() {
+        let mut builder = create_builder();
+        let actual = builder.text("1?2").map(|_| ()).unwrap_err();
+
+        assert_eq!(LineBlockError::UnknownGlyph('?'), actual);
+        assert!(builder.build_line().is_empty());
+    }
+
+    #[test]
+    fn should_build_styled_line() {
+        let actual = create_builder().number(1).build_styled_line();
+
+        assert_eq!(1, actual.len());
+        assert_eq!(
+            Some(&StyledCell::block(CellColor::BLACK)),
+            actual[0].get(0, 0)
+        );
+        assert_eq!(
+            Some(&StyledCell::block(CellColor::WHITE)),
+            actual[0].get(2, 0)
+        );
+    }
+}
@@ -0,0 +1,59 @@
+//! Terminal cells with a symbol and colours independent of a terminal library.
+//! Grids of these cells can be written to a terminal without a tui frame.
+
+use std::fmt::Display;
+
+/// Colour of a terminal cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CellColor {
+    /// Colour of the 256 colour palette. The first 16 are the standard and bright colours.
+    Indexed(u8),
+    /// True colour with red, green and blue
+    Rgb(u8, u8, u8),
+}
+
+impl CellColor {
+    pub const BLACK: Self = Self::Indexed(0);
+    pub const RED: Self = Self::Indexed(1);
+    pub const GREEN: Self = Self::Indexed(2);
+    pub const YELLOW: Self = Self::Indexed(3);
+    pub const BLUE: Self = Self::Indexed(4);
+    pub const MAGENTA: Self = Self::Indexed(5);
+    pub const CYAN: Self = Self::Indexed(6);
+    pub const GRAY: Self = Self::Indexed(7);
+    pub const DARK_GRAY: Self = Self::Indexed(8);
+    pub const WHITE: Self = Self::Indexed(15);
+}
+
+/// Symbol of a terminal cell with its colours. A colour of none is the default colour of the
+/// terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StyledCell {
+    pub symbol: char,
+    pub fg: Option<CellColor>,
+    pub bg: Option<CellColor>,
+}
+
+impl Default for StyledCell {
+    fn default() -> Self {
+        Self::new(' ', None, None)
+    }
+}
+
+impl StyledCell {
+    pub fn new(symbol: char, fg: Option<CellColor>, bg: Option<CellColor>) -> Self {
+        Self { symbol, fg, bg }
+    }
+
+    /// White space with `bg` as background like an element of a block.
+    pub fn block(bg: CellColor) -> Self {
+        Self::new(' ', None, Some(bg))
+    }
+}
+
+/// Prints only the symbol without colours
+impl Display for StyledCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol)
+    }
+}
@@ -0,0 +1,178 @@
+//! Formats durations and times of day as text with zero padded fields like `01:05:09`.
+
+use std::time::Duration;
+
+const SECONDS_PER_MINUTE: u64 = 60;
+const SECONDS_PER_HOUR: u64 = 60 * SECONDS_PER_MINUTE;
+
+/// When hours are shown for a duration
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Hours {
+    /// Hours are always shown like `00:05:09`.
+    #[default]
+    Always,
+    /// Hours are only shown for durations of at least one hour.
+    IfNeeded,
+    /// Hours are never shown and counted as minutes instead like `65:09`.
+    Never,
+}
+
+/// How a duration is formatted. Minutes and seconds are always padded to 2 digits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DurationFormat {
+    pub hours: Hours,
+    /// Milliseconds are appended with 3 digits after a decimal point like `05:09.250`.
+    pub milliseconds: bool,
+}
+
+impl DurationFormat {
+    pub fn new(hours: Hours, milliseconds: bool) -> Self {
+        Self {
+            hours,
+            milliseconds,
+        }
+    }
+}
+
+/// How the hour of a time of day is shown
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ClockStyle {
+    /// Hours from 00 to 23 like `13:05:09`
+    #[default]
+    TwentyFourHour,
+    /// Hours from 01 to 12 followed by AM or PM like `01:05:09 PM`
+    TwelveHour,
+}
+
+/// Returns `duration` as text according to `format`. Parts smaller than a millisecond are cut off.
+/// Hours are padded to at least 2 digits but are not limited to 24.
+pub fn format_duration(duration: Duration, format: DurationFormat) -> String {
+    let total_seconds = duration.as_secs();
+    let hours = total_seconds / SECONDS_PER_HOUR;
+    let seconds = total_seconds % SECONDS_PER_MINUTE;
+
+    let mut text = match format.hours {
+        Hours::IfNeeded if hours == 0 => format!("{:02}", minutes_of_hour(total_seconds)),
+        Hours::Always | Hours::IfNeeded => {
+            format!("{hours:02}:{:02}", minutes_of_hour(total_seconds))
+        }
+        Hours::Never => format!("{:02}", total_seconds / SECONDS_PER_MINUTE),
+    };
+    text.push_str(&format!(":{seconds:02}"));
+
+    if format.milliseconds {
+        text.push_str(&format!(".{:03}", duration.subsec_millis()));
+    }
+
+    text
+}
+
+/// Returns the time of day as text in `style`.
+/// Returns none if `hours` is greater than 23 or `minutes` or `seconds` greater than 59.
+pub fn format_time_of_day(
+    hours: u8,
+    minutes: u8,
+    seconds: u8,
+    style: ClockStyle,
+) -> Option<String> {
+    if hours > 23 || minutes > 59 || seconds > 59 {
+        return None;
+    }
+
+    let text = match style {
+        ClockStyle::TwentyFourHour => format!("{hours:02}:{minutes:02}:{seconds:02}"),
+        ClockStyle::TwelveHour => {
+            let suffix = if hours < 12 { "AM" } else { "PM" };
+            let hours = match hours % 12 {
+                0 => 12,
+                hours => hours,
+            };
+            format!("{hours:02}:{minutes:02}:{seconds:02} {suffix}")
+        }
+    };
+
+    Some(text)
+}
+
+fn minutes_of_hour(total_seconds: u64) -> u64 {
+    total_seconds % SECONDS_PER_HOUR / SECONDS_PER_MINUTE
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_format_duration_with_hours() {
+        let duration = Duration::from_millis(3_909_250);
+
+        assert_eq!(
+            "01:05:09",
+            format_duration(duration, DurationFormat::default())
+        );
+        assert_eq!(
+            "01:05:09.250",
+            format_duration(duration, DurationFormat::new(Hours::IfNeeded, true))
+        );
+        assert_eq!(
+            "65:09",
+            format_duration(duration, DurationFormat::new(Hours::Never, false))
+        );
+        assert_eq!(
+            "100:00:00",
+            format_duration(Duration::from_secs(360_000), DurationFormat::default())
+        );
+    }
+
+    #[test]
+    fn should_omit_hours_if_not_needed() {
+        let duration = Duration::from_millis(309_007);
+
+        assert_eq!(
+            "05:09.007",
+            format_duration(duration, DurationFormat::new(Hours::IfNeeded, true))
+        );
+        assert_eq!(
+            "00:05:09",
+            format_duration(duration, DurationFormat::new(Hours::Always, false))
+        );
+    }
+
+    #[test]
+    fn should_format_time_of_day() {
+        let twelve = ClockStyle::TwelveHour;
+
+        assert_eq!(
+            Some("13:05:09".to_string()),
+            format_time_of_day(13, 5, 9, ClockStyle::TwentyFourHour)
+        );
+        assert_eq!(
+            Some("01:05:09 PM".to_string()),
+            format_time_of_day(13, 5, 9, twelve)
+        );
+        assert_eq!(
+            Some("12:00:00 AM".to_string()),
+            format_time_of_day(0, 0, 0, twelve)
+        );
+        assert_eq!(
+            Some("12:30:00 PM".to_string()),
+            format_time_of_day(12, 30, 0, twelve)
+        );
+        assert_eq!(None, format_time_of_day(24, 0, 0, twelve));
+        assert_eq!(None, format_time_of_day(23, 60, 0, twelve));
+    }
+
+    #[test]
+    fn should_keep_width_of_twelve_hour_style() {
+        let twelve = ClockStyle::TwelveHour;
+
+        assert_eq!(
+            Some("09:00:00 AM".to_string()),
+            format_time_of_day(9, 0, 0, twelve)
+        );
+        assert_eq!(
+            Some("10:00:00 AM".to_string()),
+            format_time_of_day(10, 0, 0, twelve)
+        );
+    }
+}